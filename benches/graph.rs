@@ -0,0 +1,91 @@
+//! Benchmarks of the graph operations and of reading graph files.
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use max_clique::graph::Graph;
+use max_clique::io::{self, Policy};
+
+/// Largest instance of `db/`.
+const INSTANCE: &str = "db/C250.9.clq";
+
+/// Reads the benchmark instance.
+fn instance() -> Graph {
+  io::read_file(INSTANCE, None, Policy::Strict, None, false).unwrap().graph
+}
+
+/// Returns the edges of a graph listed once by their smallest node.
+fn edges(graph: &Graph) -> Vec<(usize, usize)> {
+  let mut edges = vec![];
+  for n in graph.nodes() {
+    edges.extend(graph.adjlst_of(n).iter().filter(|&&an| an > n)
+      .map(|&an| (n, an)));
+  }
+  edges
+}
+
+/// Returns a complete graph with the given number of nodes.
+fn complete(nodes: usize) -> Graph {
+  let mut graph = Graph::new(nodes);
+  for a in 1..=nodes {
+    for b in a + 1..=nodes { graph.insert_edge((a, b)); }
+  }
+  graph
+}
+
+fn insert_edge(c: &mut Criterion) {
+  let graph = instance();
+  let edges = edges(&graph);
+  c.bench_function("insert_edge C250.9", |b| b.iter_batched(
+    || Graph::new(graph.nlen()),
+    |mut g| {
+      for &e in &edges { g.insert_edge(e); }
+      g
+    },
+    BatchSize::LargeInput));
+}
+
+fn contains_edge(c: &mut Criterion) {
+  let graph = instance();
+  let mut rng = StdRng::seed_from_u64(41);
+  let pairs: Vec<(usize, usize)> = (0..10_000).map(|_|
+    (rng.gen_range(1..=graph.nlen()), rng.gen_range(1..=graph.nlen())))
+    .collect();
+  c.bench_function("contains_edge C250.9 x10000", |b| b.iter(||
+    pairs.iter().filter(|&&e| graph.contains_edge(black_box(e))).count()));
+}
+
+fn is_complete(c: &mut Criterion) {
+  let graph = instance();
+  let complete = complete(100);
+  c.bench_function("is_complete C250.9", |b| b.iter(||
+    black_box(&graph).is_complete()));
+  c.bench_function("is_complete K100", |b| b.iter(||
+    black_box(&complete).is_complete()));
+}
+
+fn nodes_ord_by_degree(c: &mut Criterion) {
+  let graph = instance();
+  c.bench_function("nodes_ord_by_degree C250.9", |b| b.iter(||
+    black_box(&graph).nodes_ord_by_degree()));
+}
+
+fn core_decomposition(c: &mut Criterion) {
+  let graph = instance();
+  c.bench_function("core_decomposition C250.9", |b| b.iter(||
+    black_box(&graph).core_decomposition()));
+}
+
+fn read(c: &mut Criterion) {
+  let mut group = c.benchmark_group("io");
+  group.sample_size(10);
+  group.bench_function("read C250.9", |b| b.iter(||
+    io::read_file(black_box(INSTANCE), None, Policy::Strict, None, false)
+      .unwrap()));
+  group.finish();
+}
+
+criterion_group!(benches, insert_edge, contains_edge, is_complete,
+  nodes_ord_by_degree, core_decomposition, read);
+criterion_main!(benches);
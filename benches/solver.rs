@@ -0,0 +1,43 @@
+//! Benchmarks of the solvers on random graphs with fixed seeds.
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use max_clique::graph::Graph;
+use max_clique::io::Solver;
+use max_clique::{generate, solver};
+
+/// Returns a G(n, p) random graph with a fixed seed.
+fn random(nodes: usize, p: f64, seed: u64) -> Graph {
+  generate::gnp(&mut StdRng::seed_from_u64(seed), nodes, p)
+}
+
+fn solvers(c: &mut Criterion) {
+  let mut group = c.benchmark_group("solve");
+  group.sample_size(10);
+  // Sizes that each solver finishes in a reasonable time
+  let cases = [(Solver::Backtracking, 14), (Solver::BranchAndBound, 14),
+    (Solver::BranchAndBound, 20)];
+  for &(s, nodes) in &cases {
+    let graph = random(nodes, 0.5, 41);
+    let id = BenchmarkId::new(s.to_string(), format!("G({}, 0.5)", nodes));
+    group.bench_with_input(id, &graph, |b, graph| b.iter(||
+      solver::solve(black_box(graph), &s).unwrap()));
+  }
+  group.finish();
+}
+
+fn weighted(c: &mut Criterion) {
+  let mut group = c.benchmark_group("solve_weighted");
+  group.sample_size(10);
+  let graph = random(30, 0.5, 41);
+  let mut rng = StdRng::seed_from_u64(42);
+  let weights: Vec<f64> = (0..30).map(|_| rng.gen_range(1.0..10.0)).collect();
+  group.bench_function("G(30, 0.5)", |b| b.iter(||
+    solver::solve_weighted(black_box(&graph), &weights).unwrap()));
+  group.finish();
+}
+
+criterion_group!(benches, solvers, weighted);
+criterion_main!(benches);
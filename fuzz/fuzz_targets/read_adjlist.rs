@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use max_clique::io::{self, Format, Policy};
+
+fuzz_target!(|data: &[u8]| {
+  for &policy in &[Policy::Strict, Policy::Lenient] {
+    let _ = io::read_from(data, Some(Format::AdjList), policy, None);
+  }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use std::io::Cursor;
+use libfuzzer_sys::fuzz_target;
+use max_clique::io::{self, Compression, Policy};
+
+// Detects the compression and the format of the content, as done for the
+// standard input
+fuzz_target!(|data: &[u8]| {
+  let reader = match io::decompress(Cursor::new(data.to_vec()),
+    Compression::None) {
+      Ok(reader) => reader,
+      Err(_) => return,
+    };
+  let _ = io::read_from(reader, None, Policy::Lenient, None);
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use max_clique::io::{self, Format, Policy};
+
+// Graph with numbered nodes and graph with original ids
+const DIMACS: &str = "p edge 4 3\ne 1 2\ne 2 3\ne 3 4\n";
+const EDGE_LIST: &str = "a b\nb c\nc a\n";
+
+fuzz_target!(|data: &[u8]| {
+  for &(content, format) in &[(DIMACS, Format::Dimacs),
+    (EDGE_LIST, Format::EdgeList)] {
+      let instance = io::read_from(content.as_bytes(), Some(format),
+        Policy::Strict, None).unwrap();
+      let _ = io::solution::read(data, &instance);
+  }
+});
@@ -0,0 +1,137 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use serde::Serialize;
+use crate::error::Error;
+use crate::io::report::csv_field;
+use crate::io::{Format, Instance, ReportFormat, Solver};
+use crate::solver;
+
+/// Measurements of a solver over an instance across the repetitions.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Row {
+  /// Name of the instance file.
+  pub instance: String,
+  /// Number of nodes of the graph.
+  pub nodes: usize,
+  /// Number of edges of the graph.
+  pub edges: usize,
+  /// Name of the solver.
+  pub solver: String,
+  /// Size of the largest clique found.
+  pub size: usize,
+  /// Mean wall time of the runs in seconds.
+  pub runtime: f64,
+  /// Shortest wall time of the runs in seconds.
+  pub min_runtime: f64,
+  /// Mean number of search nodes visited by the runs.
+  pub search_nodes: u64,
+  /// Number of runs that reached the time limit.
+  pub timeouts: usize,
+  /// Number of runs.
+  pub repetitions: usize,
+}
+
+/// Returns the graph files matched by a directory, which holds the files of
+/// the known formats, a file or a glob pattern, sorted by path.
+pub fn instances(pattern: &str) -> Result<Vec<PathBuf>, Error> {
+  let path = Path::new(pattern);
+  let mut paths = vec![];
+  if path.is_dir() {
+    for entry in fs::read_dir(path)? {
+      let path = entry?.path();
+      if path.is_file() && Format::from_path(&path).is_some() {
+        paths.push(path);
+      }
+    }
+  } else if path.is_file() {
+    paths.push(path.to_path_buf());
+  } else {
+    let entries = glob::glob(pattern).map_err(|e|
+      io::Error::new(io::ErrorKind::InvalidInput, e.msg))?;
+    for entry in entries {
+      let path = entry.map_err(io::Error::from)?;
+      if path.is_file() { paths.push(path); }
+    }
+  }
+  paths.sort();
+  Ok(paths)
+}
+
+/// Runs a solver over an instance the given number of times and returns the
+/// measurements.
+pub fn run(name: &str, instance: &Instance, solver: Solver,
+  time_limit: Option<Duration>, repetitions: usize) -> Result<Row, Error> {
+    let graph = &instance.graph;
+    let (mut size, mut timeouts) = (0, 0);
+    let (mut total, mut min) = (Duration::ZERO, Duration::MAX);
+    let mut search_nodes = 0;
+    for _ in 0..repetitions {
+      let solution = solver::solve_with(graph, &solver, time_limit)?;
+      size = size.max(solution.clique.nlen());
+      if !solution.optimal { timeouts += 1; }
+      total += solution.runtime;
+      min = min.min(solution.runtime);
+      search_nodes += solution.nodes;
+    }
+    Ok(Row { instance: name.to_string(), nodes: graph.nlen(),
+      edges: graph.elen(), solver: solver.to_string(), size,
+      runtime: total.as_secs_f64() / repetitions as f64,
+      min_runtime: min.as_secs_f64(),
+      search_nodes: search_nodes / repetitions as u64, timeouts, repetitions })
+}
+
+/// Writes the measurements as a table aligned in columns.
+pub fn write_table<W: Write>(mut writer: W, rows: &[Row])
+  -> Result<(), Error> {
+    let header = ["instance", "nodes", "edges", "solver", "size", "time (s)",
+      "search nodes", "timeouts"];
+    let cells: Vec<[String; 8]> = rows.iter().map(|r| [r.instance.clone(),
+      r.nodes.to_string(), r.edges.to_string(), r.solver.clone(),
+      r.size.to_string(), format!("{:.6}", r.runtime),
+      r.search_nodes.to_string(),
+      format!("{}/{}", r.timeouts, r.repetitions)]).collect();
+    // Get the width of each column
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in &cells {
+      for (w, cell) in widths.iter_mut().zip(row) { *w = (*w).max(cell.len()); }
+    }
+    // Write the rows aligning the text to the left and the numbers to the
+    // right
+    let lines = std::iter::once(header.map(String::from)).chain(cells);
+    for row in lines {
+      let mut line = String::new();
+      for (i, (cell, w)) in row.iter().zip(&widths).enumerate() {
+        if i > 0 { line.push_str("  "); }
+        if i == 0 || i == 3 { line += &format!("{:<1$}", cell, w); }
+        else { line += &format!("{:>1$}", cell, w); }
+      }
+      writeln!(writer, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+/// Writes the measurements in the given format, either as a JSON array or as
+/// CSV rows preceded by a header.
+pub fn write<W: Write>(mut writer: W, format: ReportFormat, rows: &[Row])
+  -> Result<(), Error> {
+    match format {
+      ReportFormat::Json => {
+        serde_json::to_writer_pretty(&mut writer, rows)
+          .map_err(io::Error::from)?;
+        writeln!(writer)?;
+      },
+      ReportFormat::Csv => {
+        writeln!(writer, "instance,nodes,edges,solver,size,runtime,\
+          min_runtime,search_nodes,timeouts,repetitions")?;
+        for r in rows {
+          writeln!(writer, "{},{},{},{},{},{},{},{},{},{}",
+            csv_field(&r.instance), r.nodes, r.edges, csv_field(&r.solver),
+            r.size, r.runtime, r.min_runtime, r.search_nodes, r.timeouts,
+            r.repetitions)?;
+        }
+      },
+    }
+    Ok(())
+}
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use crate::color::{self, Algorithm};
+use crate::graph::Graph;
+use crate::solver;
+
+/// Default number of iterations of the Lovász theta computation.
+pub const ITERATIONS: usize = 200;
+
+/// Largest number of Lanczos steps of an eigenvalue computation.
+pub(crate) const STEPS: usize = 300;
+
+/// Norm of the residual, relative to the eigenvalue, below which an
+/// eigenvalue computation converged.
+const TOLERANCE: f64 = 1e-10;
+
+/// Returns the dot product of two vectors.
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+  x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+/// Returns a vector scaled to unit norm, or the unit vector of equal entries
+/// if it is zero.
+fn normalized(x: &[f64]) -> Vec<f64> {
+  let norm = dot(x, x).sqrt();
+  if norm == 0.0 { return vec![1.0 / (x.len() as f64).sqrt(); x.len()] }
+  x.iter().map(|a| a / norm).collect()
+}
+
+/// Returns the largest eigenvalue of a symmetric tridiagonal matrix, given
+/// its diagonal and subdiagonal, and a respective unit eigenvector.
+///
+/// The eigenvalue is found by bisection over the Sturm sequence counts and
+/// the eigenvector by inverse iteration.
+fn tridiagonal_max_eigen(alphas: &[f64], betas: &[f64]) -> (f64, Vec<f64>) {
+  let k = alphas.len();
+  let beta = |i: usize| if i < betas.len() { betas[i].abs() } else { 0.0 };
+  // Bound the eigenvalues by the Gershgorin discs
+  let radius = |i: usize| beta(i) + if i > 0 { beta(i - 1) } else { 0.0 };
+  let mut lo = (0..k).map(|i| alphas[i] - radius(i)).fold(f64::MAX, f64::min);
+  let mut hi = (0..k).map(|i| alphas[i] + radius(i)).fold(f64::MIN, f64::max);
+  let tiny = f64::EPSILON * lo.abs().max(hi.abs()).max(1.0);
+  // Number of eigenvalues below `x`
+  let below = |x: f64| {
+    let mut d = 1.0;
+    (0..k).filter(|&i| {
+      d = alphas[i] - x - if i > 0 { betas[i - 1].powi(2) / d } else { 0.0 };
+      if d.abs() < tiny { d = -tiny; }
+      d < 0.0
+    }).count()
+  };
+  for _ in 0..200 {
+    let mid = lo + (hi - lo) / 2.0;
+    if mid <= lo || mid >= hi { break }
+    if below(mid) == k { hi = mid; } else { lo = mid; }
+  }
+  // Solve the shifted systems by elimination without pivoting, replacing
+  // the vanishing pivots
+  let mut y = vec![1.0; k];
+  for _ in 0..3 {
+    let mut c = vec![0.0; k];
+    for i in 0..k {
+      let (prev, c_prev, y_prev) = if i == 0 { (0.0, 0.0, 0.0) }
+        else { (betas[i - 1], c[i - 1], y[i - 1]) };
+      let mut pivot = alphas[i] - hi - prev * c_prev;
+      if pivot.abs() < tiny { pivot = tiny; }
+      if i < betas.len() { c[i] = betas[i] / pivot; }
+      y[i] = (y[i] - prev * y_prev) / pivot;
+    }
+    for i in (0..k.saturating_sub(1)).rev() { y[i] -= c[i] * y[i + 1]; }
+    y = normalized(&y);
+  }
+  (hi, y)
+}
+
+/// Estimate of the largest eigenvalue of a matrix.
+#[derive(Clone, Debug)]
+pub(crate) struct Eigen {
+  /// Rayleigh quotient of the vector, which is at most the eigenvalue.
+  pub value: f64,
+  /// Unit vector of the estimate.
+  pub vector: Vec<f64>,
+  /// Norm of the residual of the vector, so that an eigenvalue lies within
+  /// it from the value, although not necessarily the largest one.
+  pub residual: f64,
+}
+
+impl Eigen {
+  /// Returns true if the residual is small enough for the value to estimate
+  /// an eigenvalue, or false if the computation stopped at the number of
+  /// steps.
+  pub fn converged(&self) -> bool {
+    self.residual <= TOLERANCE * self.value.abs().max(1.0)
+  }
+}
+
+/// Symmetric matrix over the nodes of a graph, numbered from zero in
+/// ascending order, whose diagonal entries and entries of the edges are one
+/// and whose entries of the pairs of nodes that are not adjacent are free.
+///
+/// The matrix is kept as a sum of rank one corrections plus the entries of
+/// the diagonal and the edges that cancel them, so that it takes linear
+/// space in the order for each correction instead of quadratic space.
+pub(crate) struct Matrix {
+  adjlsts: Vec<Vec<usize>>,
+  /// Diagonal entries minus the ones of the corrections.
+  diagonal: Vec<f64>,
+  /// Entries of the edges minus the ones of the corrections.
+  weights: Vec<Vec<f64>>,
+  /// Corrections `c v vᵀ`, whose free entries add up to the free entries.
+  corrections: Vec<(f64, Vec<f64>)>,
+}
+
+impl Matrix {
+  /// Returns the identity plus the adjacency matrix of a graph.
+  pub fn new(graph: &Graph) -> Matrix {
+    let mut nodes = graph.nodes(); nodes.sort();
+    let index: HashMap<usize, usize> = nodes.iter().enumerate()
+      .map(|(i, &n)| (n, i)).collect();
+    let adjlsts: Vec<Vec<usize>> = nodes.iter().map(|&n| graph.adjlst_of(n)
+      .iter().map(|an| index[an]).collect()).collect();
+    let diagonal = vec![1.0; nodes.len()];
+    let weights = adjlsts.iter().map(|adjlst| vec![1.0; adjlst.len()])
+      .collect();
+    Matrix { adjlsts, diagonal, weights, corrections: vec![] }
+  }
+
+  /// Returns the order of the matrix.
+  pub fn order(&self) -> usize {
+    self.adjlsts.len()
+  }
+
+  /// Returns true if no entry is free, which is when the graph is complete.
+  fn is_complete(&self) -> bool {
+    self.adjlsts.iter().all(|adjlst| adjlst.len() + 1 == self.order())
+  }
+
+  /// Adds `c v vᵀ` to the free entries.
+  fn correct(&mut self, c: f64, v: Vec<f64>) {
+    for (i, adjlst) in self.adjlsts.iter().enumerate() {
+      self.diagonal[i] -= c * v[i] * v[i];
+      for (w, &j) in self.weights[i].iter_mut().zip(adjlst) {
+        *w -= c * v[i] * v[j];
+      }
+    }
+    self.corrections.push((c, v));
+  }
+
+  /// Returns the sum of the squares of the free entries of `v vᵀ` above the
+  /// diagonal.
+  fn free_norm(&self, v: &[f64]) -> f64 {
+    // The free entries are the entries off the diagonal and the edges
+    let squares: Vec<f64> = v.iter().map(|x| x * x).collect();
+    let total: f64 = squares.iter().sum();
+    let adjacent: f64 = self.adjlsts.iter().zip(&squares)
+      .map(|(adjlst, s)| s * adjlst.iter().map(|&j| squares[j]).sum::<f64>())
+      .sum();
+    ((total * total - dot(&squares, &squares) - adjacent) / 2.0).max(0.0)
+  }
+
+  /// Returns the product of the matrix and a vector.
+  fn product(&self, x: &[f64]) -> Vec<f64> {
+    let mut y: Vec<f64> = self.diagonal.iter().zip(x).map(|(d, a)| d * a)
+      .collect();
+    for (i, adjlst) in self.adjlsts.iter().enumerate() {
+      y[i] += adjlst.iter().zip(&self.weights[i]).map(|(&j, w)| w * x[j])
+        .sum::<f64>();
+    }
+    for (c, v) in &self.corrections {
+      let vx = c * dot(v, x);
+      for (a, b) in y.iter_mut().zip(v) { *a += vx * b; }
+    }
+    y
+  }
+
+  /// Returns an estimate of the largest eigenvalue of the matrix by at most
+  /// the given number of Lanczos steps from a vector, which stop once the
+  /// estimate converged.
+  ///
+  /// The basis of the Krylov space is orthogonalized in full, so that the
+  /// estimate is exact after as many steps as the order of the matrix.
+  pub fn max_eigen(&self, start: &[f64], steps: usize) -> Eigen {
+    let mut q = normalized(start);
+    let mut basis: Vec<Vec<f64>> = vec![];
+    let (mut alphas, mut betas) = (vec![], vec![]);
+    let mut ritz = vec![];
+    for _ in 0..steps.min(self.order()) {
+      let mut w = self.product(&q);
+      alphas.push(dot(&w, &q));
+      basis.push(q.clone());
+      // Orthogonalize twice to keep the basis orthonormal
+      for _ in 0..2 {
+        for b in &basis {
+          let d = dot(&w, b);
+          for (x, y) in w.iter_mut().zip(b) { *x -= d * y; }
+        }
+      }
+      let beta = dot(&w, &w).sqrt();
+      let (value, y) = tridiagonal_max_eigen(&alphas, &betas);
+      ritz = y;
+      // The residual of the Ritz vector is `beta` times its last entry
+      let last = ritz.last().copied().unwrap_or_default();
+      if beta * last.abs() <= TOLERANCE * value.abs().max(1.0) { break }
+      betas.push(beta);
+      q = w.iter().map(|x| x / beta).collect();
+    }
+    let vector = if basis.is_empty() { q } else {
+      let mut v = vec![0.0; self.order()];
+      for (b, &c) in basis.iter().zip(&ritz) {
+        for (x, y) in v.iter_mut().zip(b) { *x += c * y; }
+      }
+      normalized(&v)
+    };
+    let product = self.product(&vector);
+    let value = dot(&vector, &product);
+    let residual = product.iter().zip(&vector)
+      .map(|(p, v)| (p - value * v).powi(2)).sum::<f64>().sqrt();
+    Eigen { value, vector, residual }
+  }
+}
+
+/// Returns a start vector of the eigenvalue computation near a vector, with
+/// a fixed irregular perturbation so that the Krylov space does not miss
+/// the largest eigenvalue by the symmetries of the graph.
+fn start(vector: &[f64]) -> Vec<f64> {
+  let n = vector.len() as f64;
+  vector.iter().enumerate().map(|(i, v)| {
+    let r = (i as f64 * 0.618_033_988_749_895).fract() - 0.5;
+    v + 0.1 * r / n.sqrt()
+  }).collect()
+}
+
+/// Returns an estimate of the Lovász theta number of the complement of a
+/// graph, which bounds the size of its cliques, after the given number of
+/// iterations.
+///
+/// The theta number of the complement is the least largest eigenvalue of
+/// the symmetric matrices whose diagonal entries and entries of the edges of
+/// the graph are one. It is approached by subgradient steps over the other
+/// entries from the identity plus the adjacency matrix. The largest
+/// eigenvalue of each iterate is estimated by Lanczos steps, and the least
+/// estimate plus the norm of its residual is returned. The result is not a
+/// guaranteed upper bound, since the residual only bounds the distance to
+/// some eigenvalue and the Lanczos steps may miss the largest one, which the
+/// perturbed start vectors make unlikely. The iterates whose estimate did
+/// not converge or fell below the size of a clique are skipped, and the
+/// graph degree plus one bounds the first of them.
+pub fn lovasz_theta_with(graph: &Graph, iterations: usize) -> f64 {
+  let mut matrix = Matrix::new(graph);
+  let n = matrix.order();
+  if n == 0 { return 0.0 }
+  let eigen = matrix.max_eigen(&start(&vec![1.0; n]), STEPS);
+  let mut best = if eigen.converged() { eigen.value + eigen.residual }
+    else { (graph.degree() + 1) as f64 };
+  // Size of a clique, which is at most the theta number, as estimate of the
+  // optimum for the step length
+  let lower = solver::preprocess(graph).map_or(1, |r| r.incumbent.len());
+  let lower = lower as f64;
+  if matrix.is_complete() || best <= lower + 1e-9 { return best }
+  let mut vector = eigen.vector;
+  let (mut scale, mut stalled, mut value) = (1.0, 0, best);
+  for _ in 0..iterations {
+    // The subgradient of the largest eigenvalue over the free entry `(i, j)`
+    // is `2 v_i v_j`, so that a step is a correction of `v vᵀ`
+    let norm = 4.0 * matrix.free_norm(&vector);
+    if norm <= f64::EPSILON { break }
+    let step = scale * (value - lower).max(1e-3) / norm;
+    matrix.correct(-2.0 * step, vector.clone());
+    let eigen = matrix.max_eigen(&start(&vector), STEPS);
+    // The estimates below the size of the clique missed the largest
+    // eigenvalue
+    let converged = eigen.converged()
+      && eigen.value + eigen.residual >= lower - 1e-9;
+    if converged { value = eigen.value + eigen.residual; }
+    vector = eigen.vector;
+    if converged && value < best - 1e-9 {
+      best = value;
+      stalled = 0;
+      if best <= lower + 1e-9 { break }
+    } else {
+      stalled += 1;
+      if stalled == 10 { scale /= 2.0; stalled = 0; }
+    }
+  }
+  best
+}
+
+/// Returns an estimate of the Lovász theta number of the complement of a
+/// graph, which bounds the size of its cliques.
+pub fn lovasz_theta(graph: &Graph) -> f64 {
+  lovasz_theta_with(graph, ITERATIONS)
+}
+
+/// Returns the largest clique size allowed by a theta number, tolerating the
+/// rounding errors of its computation.
+pub fn theta_clique(theta: f64) -> usize {
+  (theta + 1e-6).floor() as usize
+}
+
+/// Returns the least number of colors used by the coloring heuristics, which
+/// bounds the size of the cliques of a graph.
+pub fn coloring(graph: &Graph) -> usize {
+  Algorithm::ALL.iter().map(|&a| color::color(graph, a).count()).min()
+    .unwrap_or_default()
+}
+
+/// Returns the degeneracy of a graph plus one, which bounds the size of its
+/// cliques.
+pub fn degeneracy(graph: &Graph) -> usize {
+  if graph.is_empty() { 0 } else { graph.degeneracy() + 1 }
+}
@@ -0,0 +1,363 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use crate::error::Error;
+use crate::graph::Graph;
+use crate::io::Solver;
+use crate::solver::{self, Search};
+
+/// Message of the orders that hold every node of the graph once.
+const ORDER: &str = "the order holds every node once";
+
+/// Graph coloring algorithms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+  /// Sequential greedy coloring in ascending node order.
+  Greedy,
+  /// Sequential greedy coloring in descending degree order.
+  LargestFirst,
+  /// Sequential greedy coloring in reverse degeneracy order.
+  SmallestLast,
+  /// Coloring of the node with the most distinct adjacent colors first.
+  Dsatur,
+}
+
+impl Algorithm {
+  /// All the coloring algorithms.
+  pub const ALL: [Algorithm; 4] = [Algorithm::Greedy, Algorithm::LargestFirst,
+    Algorithm::SmallestLast, Algorithm::Dsatur];
+}
+
+impl FromStr for Algorithm {
+  type Err = &'static str;
+
+  fn from_str(s: &str) -> Result<Algorithm, Self::Err> {
+    match s {
+      "greedy" => Ok(Algorithm::Greedy),
+      "largest-first" => Ok(Algorithm::LargestFirst),
+      "smallest-last" => Ok(Algorithm::SmallestLast),
+      "dsatur" => Ok(Algorithm::Dsatur),
+      _ => Err("unknown coloring algorithm"),
+    }
+  }
+}
+
+impl fmt::Display for Algorithm {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Algorithm::Greedy => write!(f, "greedy"),
+      Algorithm::LargestFirst => write!(f, "largest-first"),
+      Algorithm::SmallestLast => write!(f, "smallest-last"),
+      Algorithm::Dsatur => write!(f, "dsatur"),
+    }
+  }
+}
+
+/// Assignment of colors, numbered from zero, to the nodes of a graph such
+/// that adjacent nodes have distinct colors.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coloring {
+  colors: HashMap<usize, usize>,
+  count: usize,
+}
+
+impl Coloring {
+  /// Checks an assignment of colors against a graph and returns the
+  /// respective coloring, or an error if a node of the graph has no color, a
+  /// colored node does not belong to the graph or two adjacent nodes have
+  /// the same color.
+  pub fn new(graph: &Graph, colors: HashMap<usize, usize>)
+    -> Result<Coloring, Error> {
+      for n in graph.nodes() {
+        let color = colors.get(&n).ok_or(Error::InvalidNode(n))?;
+        for &an in graph.adjlst_of(n) {
+          if n < an && colors.get(&an) == Some(color) {
+            return Err(Error::ColorConflict(n, an))
+          }
+        }
+      }
+      if let Some(&n) = colors.keys().find(|&&n| !graph.contains_node(n)) {
+        return Err(Error::InvalidNode(n))
+      }
+      Ok(Coloring::from_colors(colors))
+  }
+
+  /// Returns the coloring of a valid assignment of colors.
+  fn from_colors(colors: HashMap<usize, usize>) -> Coloring {
+    let count = colors.values().max().map_or(0, |&c| c + 1);
+    Coloring { colors, count }
+  }
+
+  /// Returns the color of a node, if it belongs to the graph.
+  pub fn color_of(&self, n: usize) -> Option<usize> {
+    self.colors.get(&n).copied()
+  }
+
+  /// Returns the color of each node.
+  pub fn colors(&self) -> &HashMap<usize, usize> {
+    &self.colors
+  }
+
+  /// Returns the number of colors, which is an upper bound of the size of
+  /// the cliques of the graph.
+  pub fn count(&self) -> usize {
+    self.count
+  }
+
+  /// Returns the nodes of each color in ascending order.
+  pub fn classes(&self) -> Vec<Vec<usize>> {
+    let mut classes = vec![vec![]; self.count];
+    for (&n, &c) in &self.colors { classes[c].push(n); }
+    for class in classes.iter_mut() { class.sort(); }
+    classes
+  }
+}
+
+/// Colors the nodes of a graph in the given order, giving each node the
+/// least color not used by its adjacent nodes. The order must hold every
+/// node of the graph once.
+pub fn greedy(graph: &Graph, order: &[usize]) -> Result<Coloring, Error> {
+  let mut colors = HashMap::new();
+  // Last node whose adjacent nodes use each color
+  let mut used = vec![None; graph.degree() + 1];
+  for &n in order {
+    if !graph.contains_node(n) { return Err(Error::InvalidNode(n)) }
+    if colors.contains_key(&n) { return Err(Error::DuplicateNode(n)) }
+    for an in graph.adjlst_of(n) {
+      if let Some(&c) = colors.get(an) { used[c] = Some(n); }
+    }
+    let color = (0..).find(|&c| used[c] != Some(n)).unwrap_or_default();
+    colors.insert(n, color);
+  }
+  for n in graph.nodes() {
+    if !colors.contains_key(&n) { return Err(Error::InvalidNode(n)) }
+  }
+  Ok(Coloring::from_colors(colors))
+}
+
+/// Colors the nodes of a graph greedily in descending degree order.
+pub fn largest_first(graph: &Graph) -> Coloring {
+  greedy(graph, &graph.nodes_ord_by_degree()).expect(ORDER)
+}
+
+/// Colors the nodes of a graph greedily in reverse degeneracy order, which
+/// uses at most one color more than the degeneracy of the graph.
+pub fn smallest_last(graph: &Graph) -> Coloring {
+  let mut order = graph.nodes_ord_by_degeneracy(); order.reverse();
+  greedy(graph, &order).expect(ORDER)
+}
+
+/// Colors the nodes of a graph one at a time, choosing the node with the
+/// most distinct colors among its adjacent nodes and breaking ties by the
+/// largest degree, and giving it the least color not used by them.
+pub fn dsatur(graph: &Graph) -> Coloring {
+  let mut colors: HashMap<usize, usize> = HashMap::new();
+  // Distinct colors adjacent to each uncolored node
+  let mut adjacent: HashMap<usize, BTreeSet<usize>> = graph.nodes().iter()
+    .map(|&n| (n, BTreeSet::new())).collect();
+  let mut queue: BTreeSet<(usize, usize, Reverse<usize>)> = graph.nodes()
+    .iter().map(|&n| (0, graph.degree_of(n), Reverse(n))).collect();
+  while let Some((_, _, Reverse(n))) = queue.pop_last() {
+    let used = adjacent.remove(&n).unwrap_or_default();
+    let color = (0..).find(|c| !used.contains(c)).unwrap_or_default();
+    colors.insert(n, color);
+    // Update the saturation of the uncolored adjacent nodes
+    for &an in graph.adjlst_of(n) {
+      if let Some(set) = adjacent.get_mut(&an) {
+        let key = (set.len(), graph.degree_of(an), Reverse(an));
+        if set.insert(color) {
+          queue.remove(&key);
+          queue.insert((set.len(), key.1, key.2));
+        }
+      }
+    }
+  }
+  Coloring::from_colors(colors)
+}
+
+/// Colors the nodes of a graph with the given algorithm.
+pub fn color(graph: &Graph, algorithm: Algorithm) -> Coloring {
+  match algorithm {
+    Algorithm::Greedy => {
+      let mut order = graph.nodes(); order.sort();
+      greedy(graph, &order).expect(ORDER)
+    },
+    Algorithm::LargestFirst => largest_first(graph),
+    Algorithm::SmallestLast => smallest_last(graph),
+    Algorithm::Dsatur => dsatur(graph),
+  }
+}
+
+/// Result of the exact coloring of a graph.
+#[derive(Clone, Debug)]
+pub struct Chromatic {
+  /// Coloring with the least number of colors found.
+  pub coloring: Coloring,
+  /// Largest clique found, whose size is a lower bound of the number of
+  /// colors.
+  pub clique: Vec<usize>,
+  /// Whether the search finished, so that the number of colors is the
+  /// chromatic number.
+  pub optimal: bool,
+  /// Number of search nodes visited by the coloring search.
+  pub nodes: u64,
+  /// Wall time spent by the clique and coloring searches.
+  pub runtime: Duration,
+}
+
+impl Chromatic {
+  /// Returns true if the clique has as many nodes as the coloring has colors,
+  /// which proves that both are optimal, or false otherwise.
+  pub fn is_certified(&self) -> bool {
+    self.clique.len() == self.coloring.count()
+  }
+}
+
+/// State of the DSATUR branch and bound over the nodes numbered from zero.
+struct Exact<'a> {
+  adjlsts: Vec<Vec<usize>>,
+  degrees: Vec<usize>,
+  colors: Vec<Option<usize>>,
+  /// Number of adjacent nodes of each node with each color.
+  counts: Vec<Vec<usize>>,
+  /// Number of distinct colors adjacent to each node.
+  saturation: Vec<usize>,
+  best: Vec<Option<usize>>,
+  upper: usize,
+  lower: usize,
+  search: &'a mut Search,
+}
+
+impl Exact<'_> {
+  /// Gives a color to a node.
+  fn assign(&mut self, n: usize, color: usize) {
+    self.colors[n] = Some(color);
+    for &an in &self.adjlsts[n] {
+      self.counts[an][color] += 1;
+      if self.counts[an][color] == 1 { self.saturation[an] += 1; }
+    }
+  }
+
+  /// Removes the color of a node.
+  fn unassign(&mut self, n: usize, color: usize) {
+    self.colors[n] = None;
+    for &an in &self.adjlsts[n] {
+      self.counts[an][color] -= 1;
+      if self.counts[an][color] == 0 { self.saturation[an] -= 1; }
+    }
+  }
+
+  /// Colors the remaining nodes with fewer colors than the best coloring,
+  /// given the number of colored nodes and of colors used.
+  ///
+  /// The search keeps an explicit stack with a frame per colored node, so
+  /// that its depth is not limited by the size of the thread stack.
+  fn branch(&mut self, colored: usize, mut used: usize) {
+    let mut stack: Vec<Frame> = vec![];
+    loop {
+      if colored + stack.len() == self.colors.len() {
+        self.upper = used;
+        self.best = self.colors.clone();
+      } else if self.search.visit() {
+        // Choose the uncolored node of largest saturation and degree, unless
+        // the time limit was reached
+        let n = (0..self.colors.len()).filter(|&n| self.colors[n].is_none())
+          .max_by_key(|&n| (self.saturation[n], self.degrees[n]))
+          .unwrap_or_default();
+        stack.push(Frame { node: n, used, color: None });
+      }
+      // Give the next color to the last node, backtracking from the nodes
+      // whose colors were all tried
+      loop {
+        let frame = match stack.last_mut() { Some(f) => f, None => return };
+        let first = match frame.color.take() {
+          Some(color) => {
+            self.unassign(frame.node, color);
+            if self.upper == self.lower || self.search.timed_out() { return }
+            color + 1
+          },
+          None => 0,
+        };
+        // Try the used colors and a single new one, as the unused colors are
+        // interchangeable, while fewer colors than the best coloring are used
+        let upper = self.upper;
+        let counts = &self.counts[frame.node];
+        match (first..=frame.used).take_while(|&c| c + 1 < upper)
+          .find(|&c| counts[c] == 0) {
+            Some(color) => {
+              frame.color = Some(color);
+              used = frame.used.max(color + 1);
+              let n = frame.node;
+              self.assign(n, color);
+              break
+            },
+            None => { stack.pop(); },
+        }
+      }
+    }
+  }
+}
+
+/// Node being colored by the exact coloring search, with the number of
+/// colors used before it and its current color.
+struct Frame {
+  node: usize,
+  used: usize,
+  color: Option<usize>,
+}
+
+/// Colors a graph with the least number of colors by a DSATUR branch and
+/// bound, until it finishes or the time limit is reached.
+///
+/// The search starts from the DSATUR coloring as upper bound and from a
+/// maximum clique, whose nodes get distinct colors, as lower bound. The
+/// clique search and the coloring search share the time limit.
+pub fn chromatic_number(graph: &Graph, time_limit: Option<Duration>)
+  -> Result<Chromatic, Error> {
+    let start = Instant::now();
+    // Get the lower bound from a maximum clique
+    let reduction = solver::preprocess(graph)?;
+    let solution = solver::solve_reduced(graph, &reduction,
+      &Solver::BranchAndBound, time_limit)?;
+    let mut clique = solution.clique.nodes(); clique.sort();
+    let remaining = time_limit.map(|t| t.saturating_sub(start.elapsed()));
+    let mut search = Search::new(remaining);
+    // Get the upper bound from the heuristic coloring
+    let heuristic = dsatur(graph);
+    if heuristic.count() == clique.len() {
+      return Ok(Chromatic { coloring: heuristic, clique, optimal: true,
+        nodes: 0, runtime: start.elapsed() })
+    }
+    // Number the nodes from zero with the clique nodes first
+    let mut nodes = clique.clone();
+    let mut others: Vec<usize> = graph.nodes().into_iter()
+      .filter(|n| clique.binary_search(n).is_err()).collect();
+    others.sort();
+    nodes.extend(others);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate()
+      .map(|(i, &n)| (n, i)).collect();
+    let upper = heuristic.count();
+    let mut exact = Exact {
+      adjlsts: nodes.iter().map(|&n| graph.adjlst_of(n).iter()
+        .map(|an| index[an]).collect()).collect(),
+      degrees: nodes.iter().map(|&n| graph.degree_of(n)).collect(),
+      colors: vec![None; nodes.len()],
+      counts: vec![vec![0; upper]; nodes.len()],
+      saturation: vec![0; nodes.len()],
+      best: vec![],
+      upper,
+      lower: clique.len(),
+      search: &mut search,
+    };
+    // Give distinct colors to the clique nodes
+    for i in 0..clique.len() { exact.assign(i, i); }
+    exact.branch(clique.len(), clique.len());
+    let coloring = if exact.best.is_empty() { heuristic } else {
+      Coloring::from_colors(nodes.iter().zip(&exact.best)
+        .map(|(&n, c)| (n, c.unwrap_or_default())).collect())
+    };
+    Ok(Chromatic { coloring, clique, optimal: !search.timed_out(),
+      nodes: search.nodes(), runtime: start.elapsed() })
+}
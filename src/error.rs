@@ -0,0 +1,67 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Errors that can occur while reading, building or solving graphs.
+#[derive(Debug)]
+pub enum Error {
+  /// A graph file could not be parsed.
+  Parse { line: usize, column: usize, message: String },
+  /// The node does not belong to the graph.
+  InvalidNode(usize),
+  /// The node already belongs to the graph.
+  DuplicateNode(usize),
+  /// The edge does not belong to the graph.
+  InvalidEdge(usize, usize),
+  /// An I/O operation failed.
+  Io(io::Error),
+  /// The graph has no nodes.
+  EmptyGraph,
+  /// A parameter of a graph generator is out of range.
+  InvalidParameter(String),
+  /// The adjacent nodes have the same color.
+  ColorConflict(usize, usize),
+}
+
+impl Error {
+  /// Creates a parse error at the given line and column.
+  pub fn parse<S: Into<String>>(line: usize, column: usize, message: S)
+    -> Error {
+      Error::Parse { line, column, message: message.into() }
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Error::Parse { line, column, message } =>
+        write!(f, "{} at line {}, column {}", message, line, column),
+      Error::InvalidNode(n) =>
+        write!(f, "the node {} does not belong to the graph", n),
+      Error::DuplicateNode(n) =>
+        write!(f, "the node {} already belongs to the graph", n),
+      Error::InvalidEdge(a, b) =>
+        write!(f, "the edge ({}, {}) does not belong to the graph", a, b),
+      Error::Io(e) => write!(f, "{}", e),
+      Error::EmptyGraph => write!(f, "the graph is empty"),
+      Error::InvalidParameter(message) => write!(f, "{}", message),
+      Error::ColorConflict(a, b) =>
+        write!(f, "the adjacent nodes {} and {} have the same color", a, b),
+    }
+  }
+}
+
+impl error::Error for Error {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      Error::Io(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for Error {
+  fn from(e: io::Error) -> Error {
+    Error::Io(e)
+  }
+}
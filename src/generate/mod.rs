@@ -0,0 +1,192 @@
+mod random;
+mod structured;
+
+use std::fmt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use crate::error::Error;
+use crate::graph::Graph;
+
+pub use self::random::{brockington, gnm, gnp, planted};
+pub use self::structured::{barabasi_albert, geometric, hamming, johnson};
+pub use self::structured::{keller, mann, steiner_triples, watts_strogatz};
+
+/// Graph models of the generators.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Model {
+  /// G(n, p) random graph.
+  Gnp { nodes: usize, p: f64 },
+  /// G(n, m) random graph.
+  Gnm { nodes: usize, edges: usize },
+  /// G(n, p) random graph with a planted clique.
+  Planted { nodes: usize, p: f64, clique: usize },
+  /// Brockington and Culberson random graph with a hidden clique.
+  Brockington { nodes: usize, p: f64, clique: usize },
+  /// Barabási–Albert preferential attachment graph.
+  BarabasiAlbert { nodes: usize, degree: usize },
+  /// Watts–Strogatz small-world graph.
+  WattsStrogatz { nodes: usize, degree: usize, beta: f64 },
+  /// Random geometric graph of the unit square.
+  Geometric { nodes: usize, radius: f64 },
+  /// DIMACS Hamming graph.
+  Hamming { length: u32, distance: u32 },
+  /// DIMACS Johnson graph.
+  Johnson { length: u32, weight: u32, distance: u32 },
+  /// DIMACS Keller graph.
+  Keller { dimension: u32 },
+  /// DIMACS MANN graph of a Steiner triple system.
+  Mann { points: usize },
+}
+
+impl Model {
+  /// Returns the number of nodes of the graphs of the model, or `None` if it
+  /// does not fit in a `usize`.
+  pub fn nodes(&self) -> Option<usize> {
+    match *self {
+      Model::Gnp { nodes, .. } | Model::Gnm { nodes, .. } |
+      Model::Planted { nodes, .. } | Model::Brockington { nodes, .. } |
+      Model::BarabasiAlbert { nodes, .. } | Model::WattsStrogatz { nodes, .. } |
+      Model::Geometric { nodes, .. } => Some(nodes),
+      Model::Hamming { length, .. } => 1usize.checked_shl(length)
+        .filter(|_| length < 64),
+      Model::Johnson { length, weight, .. } => {
+        if length >= 64 { return None }
+        // Binomial coefficient computed incrementally
+        let mut nodes: usize = 1;
+        for i in 0..weight.min(length) as usize {
+          nodes = nodes.checked_mul(length as usize - i)? / (i + 1);
+        }
+        Some(if weight > length { 0 } else { nodes })
+      },
+      Model::Keller { dimension } => {
+        // Words with a 2 that do not differ from zero in a single position
+        let words = 4usize.checked_pow(dimension)?;
+        Some(words - 3usize.pow(dimension) - dimension as usize)
+      },
+      // Three nodes per triple of the `points (points - 1) / 6` triples and
+      // one node per point
+      Model::Mann { points } =>
+        points.checked_add(1)?.checked_mul(points).map(|n| n / 2),
+    }
+  }
+}
+
+impl fmt::Display for Model {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Model::Gnp { nodes, p } => write!(f, "gnp n={} p={}", nodes, p),
+      Model::Gnm { nodes, edges } => write!(f, "gnm n={} m={}", nodes, edges),
+      Model::Planted { nodes, p, clique } =>
+        write!(f, "planted n={} p={} k={}", nodes, p, clique),
+      Model::Brockington { nodes, p, clique } =>
+        write!(f, "brock n={} p={} k={}", nodes, p, clique),
+      Model::BarabasiAlbert { nodes, degree } =>
+        write!(f, "ba n={} m={}", nodes, degree),
+      Model::WattsStrogatz { nodes, degree, beta } =>
+        write!(f, "ws n={} k={} beta={}", nodes, degree, beta),
+      Model::Geometric { nodes, radius } =>
+        write!(f, "geometric n={} r={}", nodes, radius),
+      Model::Hamming { length, distance } =>
+        write!(f, "hamming{}-{}", length, distance),
+      Model::Johnson { length, weight, distance } =>
+        write!(f, "johnson{}-{}-{}", length, weight, distance),
+      Model::Keller { dimension } => write!(f, "keller{}", dimension),
+      Model::Mann { points } => write!(f, "MANN_a{}", points),
+    }
+  }
+}
+
+/// A generated graph with a known maximum clique, if the model has one.
+#[derive(Clone, Debug)]
+pub struct Generated {
+  /// Graph with nodes numbered from one.
+  pub graph: Graph,
+  /// Sorted nodes of the planted or hidden clique, if the model has one.
+  pub clique: Option<Vec<usize>>,
+  /// Whether the clique is maximum with high probability, which holds when
+  /// it is larger than the `2 log(n) / log(1 / p)` clique number of the
+  /// random graph of the same density.
+  pub maximum: bool,
+}
+
+impl Generated {
+  /// Returns the clique if it is maximum with high probability.
+  pub fn maximum_clique(&self) -> Option<&[usize]> {
+    self.clique.as_deref().filter(|_| self.maximum)
+  }
+}
+
+/// Returns true if a clique planted in a random graph of density `p` is
+/// larger than the cliques of the random graph with high probability.
+fn exceeds_clique_number(nodes: usize, p: f64, clique: usize) -> bool {
+  if clique == 0 { return false }
+  if p <= 0.0 { return true }
+  if p >= 1.0 { return clique == nodes }
+  clique as f64 > 2.0 * (nodes as f64).ln() / (1.0 / p).ln()
+}
+
+/// Checks if a parameter is a probability.
+fn probability(p: f64) -> Result<(), Error> {
+  if (0.0..=1.0).contains(&p) { return Ok(()) }
+  Err(Error::InvalidParameter(format!("the density {} is not in [0, 1]", p)))
+}
+
+/// Checks if a clique size fits in the graph.
+fn clique_size(nodes: usize, clique: usize) -> Result<(), Error> {
+  if clique == 0 {
+    return Err(Error::InvalidParameter(
+      "the clique must have at least one node".to_string()))
+  }
+  if clique <= nodes { return Ok(()) }
+  Err(Error::InvalidParameter(format!(
+    "a clique of {} nodes does not fit in {} nodes", clique, nodes)))
+}
+
+/// Generates a graph of the given model, which is reproducible from the
+/// seed.
+pub fn generate(model: &Model, seed: u64) -> Result<Generated, Error> {
+  match model.nodes() {
+    Some(0) => return Err(Error::EmptyGraph),
+    Some(_) => {},
+    None => return Err(Error::InvalidParameter(
+      "the graph has too many nodes".to_string())),
+  }
+  let mut rng = StdRng::seed_from_u64(seed);
+  let maximum = match *model {
+    Model::Planted { nodes, p, clique } |
+    Model::Brockington { nodes, p, clique } =>
+      exceeds_clique_number(nodes, p, clique),
+    _ => false,
+  };
+  let (graph, clique) = match *model {
+    Model::Gnp { nodes, p } => {
+      probability(p)?;
+      (gnp(&mut rng, nodes, p), None)
+    },
+    Model::Gnm { nodes, edges } => (gnm(&mut rng, nodes, edges)?, None),
+    Model::Planted { nodes, p, clique } => {
+      probability(p)?;
+      clique_size(nodes, clique)?;
+      let (graph, clique) = planted(&mut rng, nodes, p, clique);
+      (graph, Some(clique))
+    },
+    Model::Brockington { nodes, p, clique } => {
+      probability(p)?;
+      clique_size(nodes, clique)?;
+      let (graph, clique) = brockington(&mut rng, nodes, p, clique)?;
+      (graph, Some(clique))
+    },
+    Model::BarabasiAlbert { nodes, degree } =>
+      (barabasi_albert(&mut rng, nodes, degree)?, None),
+    Model::WattsStrogatz { nodes, degree, beta } =>
+      (watts_strogatz(&mut rng, nodes, degree, beta)?, None),
+    Model::Geometric { nodes, radius } =>
+      (geometric(&mut rng, nodes, radius), None),
+    Model::Hamming { length, distance } => (hamming(length, distance), None),
+    Model::Johnson { length, weight, distance } =>
+      (johnson(length, weight, distance), None),
+    Model::Keller { dimension } => (keller(dimension), None),
+    Model::Mann { points } => (mann(points)?, None),
+  };
+  Ok(Generated { graph, clique, maximum })
+}
@@ -0,0 +1,99 @@
+use rand::Rng;
+use rand::seq::index;
+use crate::error::Error;
+use crate::graph::Graph;
+
+/// Returns a G(n, p) random graph, where each edge is present with
+/// probability `p`.
+pub fn gnp<R: Rng>(rng: &mut R, nodes: usize, p: f64) -> Graph {
+  let mut graph = Graph::new(nodes);
+  for a in 1..=nodes {
+    for b in a + 1..=nodes {
+      if rng.gen_bool(p) { graph.insert_edge((a, b)); }
+    }
+  }
+  graph
+}
+
+/// Returns a G(n, m) random graph, where the `m` edges are chosen uniformly
+/// among all pairs of nodes.
+pub fn gnm<R: Rng>(rng: &mut R, nodes: usize, edges: usize)
+  -> Result<Graph, Error> {
+    let pairs = nodes * nodes.saturating_sub(1) / 2;
+    if edges > pairs {
+      return Err(Error::InvalidParameter(format!(
+        "a graph with {} nodes has at most {} edges", nodes, pairs)))
+    }
+    // Choose the edge indices, where the pairs are numbered row by row
+    let mut chosen = index::sample(rng, pairs, edges).into_vec();
+    chosen.sort_unstable();
+    let mut graph = Graph::new(nodes);
+    let (mut a, mut first) = (1, 0);
+    for i in chosen {
+      // Find the row of the pair, which has the pairs `(a, a + 1..=nodes)`
+      while i >= first + nodes - a { first += nodes - a; a += 1; }
+      graph.insert_edge((a, a + 1 + i - first));
+    }
+    Ok(graph)
+}
+
+/// Returns the nodes of a random clique of the given size, sorted.
+fn clique<R: Rng>(rng: &mut R, nodes: usize, size: usize) -> Vec<usize> {
+  let mut clique: Vec<usize> = index::sample(rng, nodes, size).iter()
+    .map(|i| i + 1).collect();
+  clique.sort_unstable();
+  clique
+}
+
+/// Returns a G(n, p) random graph with a clique of the given size planted on
+/// random nodes, which are returned too.
+pub fn planted<R: Rng>(rng: &mut R, nodes: usize, p: f64, size: usize)
+  -> (Graph, Vec<usize>) {
+    let mut graph = gnp(rng, nodes, p);
+    let clique = clique(rng, nodes, size);
+    for (i, &a) in clique.iter().enumerate() {
+      for &b in &clique[i + 1..] {
+        if !graph.contains_edge((a, b)) { graph.insert_edge((a, b)); }
+      }
+    }
+    (graph, clique)
+}
+
+/// Returns a random graph of density `p` with a hidden clique of the given
+/// size, which is returned too, following Brockington and Culberson.
+///
+/// The clique is camouflaged by balancing the expected degrees: the edges
+/// between the clique and the other nodes and the edges among the other nodes
+/// are drawn with probabilities chosen so that every node has the expected
+/// degree `p (n - 1)` of a G(n, p) graph. Fails if the clique is too large for
+/// the density.
+pub fn brockington<R: Rng>(rng: &mut R, nodes: usize, p: f64, size: usize)
+  -> Result<(Graph, Vec<usize>), Error> {
+    let degree = p * nodes.saturating_sub(1) as f64;
+    let others = (nodes - size) as f64;
+    // Probability of the edges between the clique and the other nodes
+    let q = if others > 0.0 { (degree - (size - 1) as f64) / others }
+      else { 0.0 };
+    // Probability of the edges among the other nodes
+    let r = if others > 1.0 { (degree - size as f64 * q) / (others - 1.0) }
+      else { 0.0 };
+    if !(0.0..=1.0).contains(&q) || !(0.0..=1.0).contains(&r) {
+      return Err(Error::InvalidParameter(format!(
+        "a clique of {} nodes cannot be hidden with density {}", size, p)))
+    }
+    let clique = clique(rng, nodes, size);
+    let mut in_clique = vec![false; nodes + 1];
+    for &n in &clique { in_clique[n] = true; }
+    let mut graph = Graph::new(nodes);
+    for a in 1..=nodes {
+      for b in a + 1..=nodes {
+        let p = match (in_clique[a], in_clique[b]) {
+          (true, true) => 1.0,
+          (false, false) => r,
+          _ => q,
+        };
+        if rng.gen_bool(p) { graph.insert_edge((a, b)); }
+      }
+    }
+    Ok((graph, clique))
+}
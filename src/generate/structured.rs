@@ -0,0 +1,254 @@
+use rand::Rng;
+use crate::error::Error;
+use crate::graph::Graph;
+
+/// Returns a Barabási–Albert preferential attachment graph, which starts
+/// from a clique of `degree + 1` nodes and attaches each new node to
+/// `degree` distinct nodes chosen with probability proportional to their
+/// degree.
+pub fn barabasi_albert<R: Rng>(rng: &mut R, nodes: usize, degree: usize)
+  -> Result<Graph, Error> {
+    if degree == 0 || degree >= nodes {
+      return Err(Error::InvalidParameter(format!(
+        "the attachment degree must be in 1..{}", nodes)))
+    }
+    let mut graph = Graph::new(nodes);
+    // Nodes repeated once per incident edge
+    let mut ends = vec![];
+    for a in 1..=degree + 1 {
+      for b in a + 1..=degree + 1 {
+        graph.insert_edge((a, b));
+        ends.extend([a, b]);
+      }
+    }
+    let mut targets = vec![];
+    for n in degree + 2..=nodes {
+      targets.clear();
+      while targets.len() < degree {
+        let t = ends[rng.gen_range(0..ends.len())];
+        if !targets.contains(&t) { targets.push(t); }
+      }
+      for &t in &targets {
+        graph.insert_edge((n, t));
+        ends.extend([n, t]);
+      }
+    }
+    Ok(graph)
+}
+
+/// Returns a Watts–Strogatz small-world graph: a ring where each node is
+/// adjacent to its `degree` nearest nodes, whose edges are rewired to a
+/// random node with probability `beta`.
+pub fn watts_strogatz<R: Rng>(rng: &mut R, nodes: usize, degree: usize,
+  beta: f64) -> Result<Graph, Error> {
+    if !degree.is_multiple_of(2) || degree >= nodes {
+      return Err(Error::InvalidParameter(format!(
+        "the ring degree must be even and less than {}", nodes)))
+    }
+    if !(0.0..=1.0).contains(&beta) {
+      return Err(Error::InvalidParameter(format!(
+        "the rewiring probability {} is not in [0, 1]", beta)))
+    }
+    // Build the ring lattice
+    let mut graph = Graph::new(nodes);
+    for a in 1..=nodes {
+      for j in 1..=degree / 2 {
+        graph.insert_edge((a, (a + j - 1) % nodes + 1));
+      }
+    }
+    // Rewire each lattice edge, unless its node is adjacent to all others
+    for j in 1..=degree / 2 {
+      for a in 1..=nodes {
+        let b = (a + j - 1) % nodes + 1;
+        if !rng.gen_bool(beta) || graph.degree_of(a) == nodes - 1 { continue; }
+        let c = loop {
+          let c = rng.gen_range(1..=nodes);
+          if c != a && !graph.contains_edge((a, c)) { break c }
+        };
+        graph.remove_edge((a, b));
+        graph.insert_edge((a, c));
+      }
+    }
+    Ok(graph)
+}
+
+/// Returns a random geometric graph, where the nodes are random points of the
+/// unit square that are adjacent if their distance is at most `radius`.
+pub fn geometric<R: Rng>(rng: &mut R, nodes: usize, radius: f64) -> Graph {
+  let points: Vec<(f64, f64)> = (0..nodes).map(|_| (rng.gen(), rng.gen()))
+    .collect();
+  let mut graph = Graph::new(nodes);
+  for (i, a) in points.iter().enumerate() {
+    for (j, b) in points.iter().enumerate().skip(i + 1) {
+      if (a.0 - b.0).hypot(a.1 - b.1) <= radius {
+        graph.insert_edge((i + 1, j + 1));
+      }
+    }
+  }
+  graph
+}
+
+/// Returns a graph whose nodes are the given words, adjacent if they differ
+/// in at least `distance` bits.
+fn distance_graph(words: &[u64], distance: u32) -> Graph {
+  let mut graph = Graph::new(words.len());
+  for (i, a) in words.iter().enumerate() {
+    for (j, b) in words.iter().enumerate().skip(i + 1) {
+      if (a ^ b).count_ones() >= distance { graph.insert_edge((i + 1, j + 1)); }
+    }
+  }
+  graph
+}
+
+/// Returns the DIMACS `hamming<length>-<distance>` graph, whose nodes are
+/// the binary words of the given length, adjacent if their Hamming distance
+/// is at least `distance`.
+pub fn hamming(length: u32, distance: u32) -> Graph {
+  let words: Vec<u64> = (0..1 << length).collect();
+  distance_graph(&words, distance)
+}
+
+/// Returns the DIMACS `johnson<length>-<weight>-<distance>` graph, whose
+/// nodes are the binary words of the given length and weight, adjacent if
+/// their Hamming distance is at least `distance`.
+pub fn johnson(length: u32, weight: u32, distance: u32) -> Graph {
+  let mut words: Vec<u64> = vec![];
+  if weight <= length {
+    // Enumerate the words of the given weight in ascending order, where the
+    // next word moves the lowest block of ones as Gosper's hack does
+    let mut word: u64 = (1 << weight) - 1;
+    while word >> length == 0 {
+      words.push(word);
+      if word == 0 { break }
+      let low = word & word.wrapping_neg();
+      let next = word + low;
+      word = (((next ^ word) >> 2) / low) | next;
+    }
+  }
+  distance_graph(&words, distance)
+}
+
+/// Returns true if two words of `{0, 1, 2, 3}^dimension`, packed two bits
+/// per position, are adjacent in the Keller graph: they differ in at least
+/// two positions and in some position by exactly 2 modulo 4.
+fn keller_adjacent(a: usize, b: usize, dimension: u32) -> bool {
+  let (mut differ, mut by_two) = (0, false);
+  for i in 0..dimension {
+    let (x, y) = ((a >> (2 * i)) & 3, (b >> (2 * i)) & 3);
+    if x != y { differ += 1; }
+    if (x + 4 - y) % 4 == 2 { by_two = true; }
+  }
+  differ >= 2 && by_two
+}
+
+/// Returns the DIMACS `keller<dimension>` graph, which is the subgraph of the
+/// Keller graph of `{0, 1, 2, 3}^dimension` induced by the adjacent words of
+/// the zero word, in ascending order. For instance, `keller(4)` is `keller4`
+/// with 171 nodes.
+///
+/// The maximum cliques of the Keller graph can be assumed to hold the zero
+/// word, so that the clique number of this graph is one less.
+pub fn keller(dimension: u32) -> Graph {
+  let words: Vec<usize> = (0..1 << (2 * dimension))
+    .filter(|&w| keller_adjacent(0, w, dimension)).collect();
+  let mut graph = Graph::new(words.len());
+  for (i, &a) in words.iter().enumerate() {
+    for (j, &b) in words.iter().enumerate().skip(i + 1) {
+      if keller_adjacent(a, b, dimension) {
+        graph.insert_edge((i + 1, j + 1));
+      }
+    }
+  }
+  graph
+}
+
+/// Returns the triples of a Steiner triple system of `points` points, in
+/// which each pair of points lies in exactly one triple, or an error if the
+/// number of points is not 1 or 3 modulo 6.
+///
+/// When the number of points is a power `3^d`, as in the DIMACS instances,
+/// the triples are the lines `{x, y, z}` with `x + y + z = 0` of the affine
+/// space `AG(d, 3)`. Otherwise they follow the construction of Bose for 3
+/// modulo 6 points and the one of Skolem for 1 modulo 6 points.
+pub fn steiner_triples(points: usize) -> Result<Vec<[usize; 3]>, Error> {
+  if points % 6 != 1 && points % 6 != 3 {
+    return Err(Error::InvalidParameter(format!(
+      "no Steiner triple system has {} points", points)))
+  }
+  let mut dimension = 0;
+  while 3usize.pow(dimension) < points { dimension += 1; }
+  let mut triples = vec![];
+  if 3usize.pow(dimension) == points {
+    // Returns the point with the coordinates `-(x + y)` modulo 3
+    let third = |mut x: usize, mut y: usize| {
+      let (mut z, mut unit) = (0, 1);
+      for _ in 0..dimension {
+        z += (6 - x % 3 - y % 3) % 3 * unit;
+        x /= 3; y /= 3; unit *= 3;
+      }
+      z
+    };
+    for x in 0..points {
+      for y in x + 1..points {
+        let z = third(x, y);
+        if z > y { triples.push([x, y, z]); }
+      }
+    }
+    return Ok(triples)
+  }
+  // Point `(x, i)` is `3 x + i` and the last point is the infinite one
+  let n = points / 3;
+  let point = |x: usize, i: usize| 3 * x + i % 3;
+  // Commutative quasigroup of `0..n`, idempotent for odd `n` and
+  // half-idempotent for even `n`
+  let product = |x: usize, y: usize| {
+    let sum = (x + y) % n;
+    if sum.is_multiple_of(2) { sum / 2 } else { (sum + n) / 2 }
+  };
+  if n % 2 == 1 {
+    for x in 0..n { triples.push([point(x, 0), point(x, 1), point(x, 2)]); }
+  } else {
+    let (m, infinite) = (n / 2, points - 1);
+    for x in 0..m {
+      triples.push([point(x, 0), point(x, 1), point(x, 2)]);
+      for i in 0..3 {
+        triples.push([point(x + m, i), point(x, i + 1), infinite]);
+      }
+    }
+  }
+  for x in 0..n {
+    for y in x + 1..n {
+      for i in 0..3 {
+        triples.push([point(x, i), point(y, i), point(product(x, y), i + 1)]);
+      }
+    }
+  }
+  Ok(triples)
+}
+
+/// Returns the DIMACS `MANN_a<points>` graph of the Steiner triple system
+/// of `points` points given by [`steiner_triples`].
+///
+/// The graph is the complement of the conflict graph of the set covering
+/// problem of the triple system: each triple has one node per point of it,
+/// followed by one node per point, where the nodes of a triple are pairwise
+/// non-adjacent and each point node is non-adjacent to the nodes of the
+/// triples holding its point. For instance, `mann(9)` is `MANN_a9`.
+pub fn mann(points: usize) -> Result<Graph, Error> {
+  let triples = steiner_triples(points)?;
+  // Node `3 t + i + 1` is the point `triples[t][i]` of the triple `t` and
+  // node `3 T + x + 1` is the point `x`
+  let (nodes, first) = (3 * triples.len() + points, 3 * triples.len());
+  let mut graph = Graph::new(nodes);
+  for a in 0..first {
+    for b in a + 1..nodes {
+      let conflict = if b < first { a / 3 == b / 3 }
+        else { triples[a / 3][a % 3] == b - first };
+      if !conflict { graph.insert_edge((a + 1, b + 1)); }
+    }
+  }
+  for a in first..nodes {
+    for b in a + 1..nodes { graph.insert_edge((a + 1, b + 1)); }
+  }
+  Ok(graph)
+}
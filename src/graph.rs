@@ -1,14 +1,25 @@
 use std::cmp;
 use std::collections::HashMap;
+use std::fmt;
+use crate::error::Error;
 
 /// Adjacency matrix.
 type AdjMtx = HashMap<usize, Vec<usize>>;
 
 /// This struct represents a graph.
-#[derive(Default, Clone, Debug, Eq, PartialEq)]
+#[derive(Default, Clone, Eq, PartialEq)]
 pub struct Graph {
   degree: usize,
   adjmtx: AdjMtx,
+  /// Number of nodes whose degree is the graph degree.
+  degree_count: usize,
+}
+
+impl fmt::Debug for Graph {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Graph").field("degree", &self.degree)
+      .field("adjmtx", &self.adjmtx).finish()
+  }
 }
 
 impl Graph {
@@ -17,7 +28,7 @@ impl Graph {
     assert!(nodes != 0, "The number of nodes cannot be zero.");
     let mut adjmtx = AdjMtx::new();
     for n in 1..=nodes { adjmtx.insert(n, vec![]); }
-    Graph { adjmtx, degree: 0 }
+    Graph { adjmtx, degree: 0, degree_count: nodes }
   }
 
   /// Returns the graph degree.
@@ -47,6 +58,73 @@ impl Graph {
     nds_dgs.iter().map(|(_, n)| *n).collect()
   }
 
+  /// Returns the nodes of the graph in degeneracy order, where each node has
+  /// at most as many adjacent nodes after it as its core number, with the
+  /// core number of each node.
+  ///
+  /// The core number of a node is the largest `k` such that it belongs to a
+  /// subgraph whose nodes have at least `k` adjacent nodes in it. The core
+  /// numbers do not decrease along the order. This is the linear time
+  /// algorithm of Batagelj and Zaversnik.
+  pub fn core_decomposition(&self) -> (Vec<usize>, HashMap<usize, usize>) {
+    let mut nodes = self.nodes(); nodes.sort();
+    let index: HashMap<usize, usize> = nodes.iter().enumerate()
+      .map(|(i, &n)| (n, i)).collect();
+    let mut degrees: Vec<usize> = nodes.iter().map(|&n| self.degree_of(n))
+      .collect();
+    let max = degrees.iter().copied().max().unwrap_or(0);
+    // Sort the nodes by degree with a bucket per degree, where `bins[d]` is
+    // the position of the first node of degree `d`
+    let mut bins = vec![0; max + 1];
+    for &d in &degrees { bins[d] += 1; }
+    let mut start = 0;
+    for bin in bins.iter_mut() { let len = *bin; *bin = start; start += len; }
+    let mut order = vec![0; nodes.len()];
+    let mut positions = vec![0; nodes.len()];
+    for (i, &d) in degrees.iter().enumerate() {
+      positions[i] = bins[d];
+      order[bins[d]] = i;
+      bins[d] += 1;
+    }
+    for d in (1..=max).rev() { bins[d] = bins[d - 1]; }
+    bins[0] = 0;
+    // Remove the nodes in order moving their adjacent nodes to the bucket of
+    // their decreased degree
+    for i in 0..nodes.len() {
+      let a = order[i];
+      for an in &self.adjmtx[&nodes[a]] {
+        let b = index[an];
+        if degrees[b] <= degrees[a] { continue; }
+        let (d, p) = (degrees[b], positions[b]);
+        let (q, c) = (bins[d], order[bins[d]]);
+        if b != c {
+          positions[b] = q; order[p] = c;
+          positions[c] = p; order[q] = b;
+        }
+        bins[d] += 1;
+        degrees[b] -= 1;
+      }
+    }
+    let cores = nodes.iter().zip(degrees).map(|(&n, d)| (n, d)).collect();
+    (order.into_iter().map(|i| nodes[i]).collect(), cores)
+  }
+
+  /// Returns the list of nodes of the graph in degeneracy order.
+  pub fn nodes_ord_by_degeneracy(&self) -> Vec<usize> {
+    self.core_decomposition().0
+  }
+
+  /// Returns the core number of each node of the graph.
+  pub fn core_numbers(&self) -> HashMap<usize, usize> {
+    self.core_decomposition().1
+  }
+
+  /// Returns the degeneracy of the graph, which is its largest core number,
+  /// so that its cliques have at most one node more.
+  pub fn degeneracy(&self) -> usize {
+    self.core_numbers().values().copied().max().unwrap_or(0)
+  }
+
   /// Returns the list of edges of the graph.
   pub fn edges(&self) -> Vec<(usize, usize)> {
     let mut edges: Vec<(usize, usize)> = vec![];
@@ -66,49 +144,105 @@ impl Graph {
 
   /// Inserts a new node in the graph.
   pub fn insert_node(&mut self, n: usize) {
-    assert!(!self.contains_node(n),
-      "The given node already belongs to the graph");
+    self.try_insert_node(n).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  /// Inserts a new node in the graph or returns an error if the node already
+  /// belongs to the graph.
+  pub fn try_insert_node(&mut self, n: usize) -> Result<(), Error> {
+    if self.contains_node(n) { return Err(Error::DuplicateNode(n)) }
     self.adjmtx.insert(n, vec![]);
+    self.grow_degree(0);
+    Ok(())
   }
 
   /// Inserts an edge in the graph.
-  pub fn insert_edge(&mut self, (a, b): (usize, usize)) {
-    if let Some(lst) = self.adjmtx.get_mut(&a) {
-      lst.push(b);
-      self.degree = cmp::max(self.degree, lst.len());
-    }
-    else { panic!("The node {} does not belong to this graph.", a); }
-    if let Some(lst) = self.adjmtx.get_mut(&b) {
-      lst.push(a);
-      self.degree = cmp::max(self.degree, lst.len());
-    }
-    else { panic!("The node {} does not belong to this graph.", b); }
+  pub fn insert_edge(&mut self, e: (usize, usize)) {
+    self.try_insert_edge(e).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  /// Inserts an edge in the graph or returns an error if at least one of its
+  /// nodes does not belong to the graph.
+  pub fn try_insert_edge(&mut self, (a, b): (usize, usize))
+    -> Result<(), Error> {
+      if !self.contains_node(a) { return Err(Error::InvalidNode(a)) }
+      if !self.contains_node(b) { return Err(Error::InvalidNode(b)) }
+      for &(x, y) in &[(a, b), (b, a)] {
+        if let Some(lst) = self.adjmtx.get_mut(&x) {
+          lst.push(y);
+          let len = lst.len();
+          self.grow_degree(len);
+        }
+      }
+      Ok(())
   }
 
   /// Removes a node from the graph.
   pub fn remove_node(&mut self, n: usize) {
-    assert!(self.contains_node(n),
-      "This graph does not contains the given node.");
-    self.adjmtx.remove(&n);
-    for (_, v) in self.adjmtx.iter_mut() {
-      if let Some(index) = v.iter().position(|x| *x == n) { v.remove(index); }
+    self.try_remove_node(n).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  /// Removes a node from the graph or returns an error if the node does not
+  /// belong to the graph.
+  pub fn try_remove_node(&mut self, n: usize) -> Result<(), Error> {
+    if !self.contains_node(n) { return Err(Error::InvalidNode(n)) }
+    let adjlst = self.adjmtx.remove(&n).unwrap_or_default();
+    self.shrink_degree(adjlst.len());
+    for an in adjlst {
+      if let Some(v) = self.adjmtx.get_mut(&an) {
+        let len = v.len();
+        if let Some(index) = v.iter().position(|x| *x == n) { v.remove(index); }
+        self.shrink_degree(len);
+      }
     }
+    Ok(())
   }
 
   /// Removes an edge from the graph.
-  pub fn remove_edge(&mut self, (a, b): (usize, usize)) {
-    assert!(self.contains_node(a) && self.contains_node(b),
-      "This graph does not contains at least one of the given nodes.");
-    assert!(self.adjmtx[&a].contains(&b) && self.adjmtx[&b].contains(&a),
-      "The first node is not adjacent to the second one");
-    if let Some(v) = self.adjmtx.get_mut(&a) {
-      if let Some(index) = v.iter().position(|x| *x == b) { v.remove(index); }
-    }
-    if let Some(v) = self.adjmtx.get_mut(&b) {
-      if let Some(index) = v.iter().position(|x| *x == a) { v.remove(index); }
+  pub fn remove_edge(&mut self, e: (usize, usize)) {
+    self.try_remove_edge(e).unwrap_or_else(|e| panic!("{}", e))
+  }
+
+  /// Removes an edge from the graph or returns an error if the edge does not
+  /// belong to the graph.
+  pub fn try_remove_edge(&mut self, (a, b): (usize, usize))
+    -> Result<(), Error> {
+      if !self.contains_node(a) { return Err(Error::InvalidNode(a)) }
+      if !self.contains_node(b) { return Err(Error::InvalidNode(b)) }
+      if !self.contains_edge((a, b)) { return Err(Error::InvalidEdge(a, b)) }
+      for &(x, y) in &[(a, b), (b, a)] {
+        if let Some(v) = self.adjmtx.get_mut(&x) {
+          let len = v.len();
+          if let Some(index) = v.iter().position(|z| *z == y) {
+            v.remove(index);
+          }
+          self.shrink_degree(len);
+        }
+      }
+      Ok(())
+  }
+
+  /// Updates the graph degree after the degree of a node grew to `len`.
+  fn grow_degree(&mut self, len: usize) {
+    match len.cmp(&self.degree) {
+      cmp::Ordering::Greater => { self.degree = len; self.degree_count = 1; },
+      cmp::Ordering::Equal => self.degree_count += 1,
+      cmp::Ordering::Less => (),
     }
   }
 
+  /// Updates the graph degree after the degree of a node shrank from `len`
+  /// or the node was removed. The degree is recomputed only when no other
+  /// node has it, so that bulk removals rarely scan the whole graph.
+  fn shrink_degree(&mut self, len: usize) {
+    if len != self.degree { return }
+    self.degree_count -= 1;
+    if self.degree_count > 0 { return }
+    self.degree = self.adjmtx.values().map(Vec::len).max().unwrap_or(0);
+    self.degree_count = self.adjmtx.values()
+      .filter(|v| v.len() == self.degree).count();
+  }
+
   /// Returns true if the graph contains the node and false otherwise.
   pub fn contains_node(&self, n: usize) -> bool {
     self.adjmtx.contains_key(&n)
@@ -134,7 +268,7 @@ impl Graph {
       // Check if all nodes are adjacent to each other
       for k2 in self.adjmtx.keys() {
         if k1 == k2 { continue; }
-        if !self.adjmtx[k1].contains(&k2) { return false }
+        if !self.adjmtx[k1].contains(k2) { return false }
       }
     }
     true
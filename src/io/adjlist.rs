@@ -0,0 +1,73 @@
+use std::io::{BufRead, Write};
+use crate::error::Error;
+use crate::graph::Graph;
+use super::{EdgeSet, IdMap, Instance, Policy};
+
+/// Reads an adjacency list file and returns the respective graph.
+///
+/// Each line holds the id of a node followed by the ids of its adjacent
+/// nodes, which may be any string. Lines starting with `#` or `%` are
+/// comments. An edge may be listed by both of its nodes, but self-loops and
+/// edges listed twice by the same node are handled according to the policy.
+/// The nodes are numbered from one in order of appearance and their ids are
+/// kept in the instance.
+pub fn read<R: BufRead>(mut reader: R, policy: Policy)
+  -> Result<Instance, Error> {
+    let mut graph = Graph::default();
+    let mut ids = IdMap::default();
+    let mut diagnostics = vec![];
+    let mut edges = EdgeSet::default();
+    let mut line = String::new();
+    let mut ln = 0;
+    loop {
+      // Read the next line reusing the line buffer
+      line.clear();
+      if reader.read_line(&mut line)? == 0 { break }
+      ln += 1;
+      let mut tks = line.split_whitespace()
+        .map(|t| (t.as_ptr() as usize - line.as_ptr() as usize + 1, t));
+      // Ignore empty lines and comments
+      let (_, id) = match tks.next() {
+        Some((_, id)) if id.starts_with('#') || id.starts_with('%') => continue,
+        Some(t) => t,
+        None => continue,
+      };
+      let a = ids.insert(id, &mut graph);
+      for (column, id) in tks {
+        let b = ids.insert(id, &mut graph);
+        // Check for self-loops and duplicate edges
+        if a == b {
+          policy.report(&mut diagnostics, ln, column,
+            format!("self-loop on node {}", id))?;
+        } else {
+          let (edge, arc) = edges.insert_arc((a, b));
+          if !arc {
+            policy.report(&mut diagnostics, ln, column,
+              format!("duplicate adjacent node {}", id))?;
+          } else if edge {
+            graph.try_insert_edge((a, b))?;
+          }
+        }
+      }
+    }
+    if graph.is_empty() { return Err(Error::EmptyGraph) }
+    Ok(Instance { graph, ids: Some(ids.into_ids()), weights: None,
+      diagnostics })
+}
+
+/// Writes a graph as an adjacency list, where each edge is listed only by its
+/// smallest node. The nodes are renumbered from one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph) -> Result<(), Error> {
+  let mut nodes = graph.nodes(); nodes.sort();
+  let index = super::index(&nodes);
+  for n in nodes {
+    let a = index[&n];
+    let mut adjlst: Vec<usize> = graph.adjlst_of(n).iter()
+      .map(|an| index[an]).filter(|&b| b > a).collect();
+    adjlst.sort();
+    write!(writer, "{}", a)?;
+    for an in adjlst { write!(writer, " {}", an)?; }
+    writeln!(writer)?;
+  }
+  Ok(())
+}
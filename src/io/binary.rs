@@ -0,0 +1,107 @@
+use std::cmp;
+use std::io::{BufRead, Read, Write};
+use crate::error::Error;
+use crate::graph::Graph;
+use super::dimacs::{self, MAX_TOKENS};
+use super::{Instance, Policy};
+
+/// Returns the number of bytes of the bitmap row of the `i`-th node (starting
+/// from zero), which holds the bits of the nodes `0..=i`.
+fn row_len(i: usize) -> usize {
+  (i + 8) / 8
+}
+
+/// Reads a DIMACS binary `.clq.b` file and returns the respective graph with
+/// the diagnostics collected while parsing.
+///
+/// The file starts with the length in bytes of a text preamble, which holds
+/// comments and the `p col <nodes> <edges>` line, followed by the lower
+/// triangular adjacency matrix packed row by row with the most significant
+/// bit first. Parse errors in the bitmap are reported at the line following
+/// the preamble with the byte offset in the bitmap as column.
+pub fn read<R: BufRead>(mut reader: R, policy: Policy)
+  -> Result<Instance, Error> {
+    let mut diagnostics = vec![];
+    // Read the preamble length
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let length = line.trim().parse::<usize>()
+      .map_err(|_| Error::parse(1, 1, "invalid preamble length"))?;
+    // Read the preamble, whose length is not trusted for the allocation
+    let mut preamble = vec![];
+    reader.by_ref().take(length as u64).read_to_end(&mut preamble)?;
+    if preamble.len() != length {
+      return Err(Error::parse(2, 1, "truncated preamble"))
+    }
+    let preamble = String::from_utf8(preamble)
+      .map_err(|_| Error::parse(2, 1, "invalid preamble"))?;
+    // Parse the preamble
+    let mut header = None;
+    let mut ln = 1;
+    for line in preamble.lines() {
+      ln += 1;
+      let mut tks = [(0, ""); MAX_TOKENS];
+      let len = dimacs::tokens(line, &mut tks);
+      // Ignore empty lines and comments
+      if len == 0 || tks[0].1 == "c" { continue; }
+      if tks[0].1 != "p" || header.is_some() {
+        return Err(Error::parse(ln, tks[0].0,
+          format!("unexpected line type `{}` in the preamble", tks[0].1)))
+      }
+      let tks = &tks[..cmp::min(len, MAX_TOKENS)];
+      header = Some((ln, dimacs::problem(ln, tks)?));
+    }
+    let (pln, (nlen, elen)) = match header {
+      Some(header) => header,
+      None => return Err(Error::parse(ln, 1, "missing problem line")),
+    };
+    ln += 1;
+    // Read the bitmap row by row
+    let mut graph = Graph::new(nlen);
+    let mut row = vec![0; row_len(nlen)];
+    let (mut count, mut offset) = (0, 0);
+    for i in 0..nlen {
+      let row = &mut row[..row_len(i)];
+      reader.read_exact(row)
+        .map_err(|_| Error::parse(ln, offset + 1, "truncated bitmap"))?;
+      for j in 0..=i {
+        if row[j / 8] & (0x80 >> (j % 8)) == 0 { continue; }
+        count += 1;
+        if i == j {
+          policy.report(&mut diagnostics, ln, offset + j / 8 + 1,
+            format!("self-loop on node {}", i + 1))?;
+        } else {
+          graph.try_insert_edge((i + 1, j + 1))?;
+        }
+      }
+      offset += row.len();
+    }
+    // Check if the graph has the right number of edges
+    if count != elen {
+      policy.report(&mut diagnostics, pln, 1,
+        format!("expected {} edges but found {}", elen, count))?;
+    }
+    Ok(Instance { graph, ids: None, weights: None, diagnostics })
+}
+
+/// Writes a graph in the DIMACS binary `.clq.b` format. The nodes are
+/// renumbered from one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph) -> Result<(), Error> {
+  let mut nodes = graph.nodes(); nodes.sort();
+  let index = super::index(&nodes);
+  // Write the preamble and its length
+  let preamble = format!("p col {} {}\n", graph.nlen(), graph.elen());
+  write!(writer, "{}\n{}", preamble.len(), preamble)?;
+  // Write the bitmap row by row
+  let mut row = vec![0u8; row_len(nodes.len())];
+  for (i, n) in nodes.iter().enumerate() {
+    let row = &mut row[..row_len(i)];
+    for b in row.iter_mut() { *b = 0; }
+    for an in graph.adjlst_of(*n) {
+      let j = index[an] - 1;
+      if j < i { row[j / 8] |= 0x80 >> (j % 8); }
+    }
+    writer.write_all(row)?;
+  }
+  Ok(())
+}
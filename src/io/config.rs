@@ -0,0 +1,645 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use clap::ArgMatches;
+use crate::bounds;
+use crate::color::Algorithm;
+use crate::generate::Model;
+use super::{Format, Overwrite, Policy, ReportFormat};
+
+/// Options of solver for maximum clique problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Solver {
+  Backtracking,
+  BranchAndBound,
+}
+
+impl Solver {
+  /// All the solvers.
+  pub const ALL: [Solver; 2] = [Solver::Backtracking, Solver::BranchAndBound];
+}
+
+impl FromStr for Solver {
+  type Err = &'static str;
+
+  fn from_str(s: &str) -> Result<Solver, Self::Err> {
+    match s {
+      "Backtracking" => Ok(Solver::Backtracking),
+      "BranchAndBound" => Ok(Solver::BranchAndBound),
+      _ => Err("unknown solver"),
+    }
+  }
+}
+
+impl fmt::Display for Solver {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Solver::Backtracking => write!(f, "Backtracking"),
+      Solver::BranchAndBound => write!(f, "BranchAndBound"),
+    }
+  }
+}
+
+/// Parses a time limit given in seconds.
+fn time_limit(matches: &ArgMatches)
+  -> Result<Option<Duration>, &'static str> {
+    match matches.value_of("time-limit") {
+      Some(secs) => match secs.parse::<f64>() {
+        Ok(secs) if secs.is_finite() && secs > 0.0 =>
+          Ok(Some(Duration::from_secs_f64(secs))),
+        _ => Err("the time limit must be a positive number of seconds"),
+      },
+      None => Ok(None),
+    }
+}
+
+/// Reading configuration.
+pub struct Config {
+  filename: String,
+  solver: Solver,
+  format: Option<Format>,
+  policy: Policy,
+  progress: bool,
+  weighted: bool,
+  preprocess: bool,
+  weight_attr: Option<String>,
+  dot: Option<String>,
+  dot_clique: bool,
+  save: bool,
+  output: Option<PathBuf>,
+  output_dir: Option<PathBuf>,
+  overwrite: Overwrite,
+  time_limit: Option<Duration>,
+  report: Option<String>,
+  report_format: Option<ReportFormat>,
+}
+
+impl Config {
+  /// Validates the arguments and returns the reading configuration.
+  pub fn new(matches: &ArgMatches) -> Result<Config, &'static str> {
+    // Get query filename from arguments
+    if let Some(filename) = matches.value_of("filename") {
+      // Convert filename to string
+      let filename = filename.to_string();
+      // Check if there are still arguments
+      let solver = match matches.value_of("solver") {
+        Some(solver) => solver.parse()?,
+        None => Solver::Backtracking,
+      };
+      // Get the file format
+      let format = match matches.value_of("format") {
+        Some(format) => Some(format.parse()?),
+        None => None,
+      };
+      // Get the parsing policy
+      let policy = if matches.is_present("lenient") { Policy::Lenient }
+        else { Policy::Strict };
+      // Get the report format
+      let report_format = match matches.value_of("report-format") {
+        Some(format) => Some(format.parse()?),
+        None => None,
+      };
+      // Get the overwrite policy of the result file
+      let overwrite = match matches.value_of("overwrite") {
+        Some(overwrite) => overwrite.parse()?,
+        None => Overwrite::Replace,
+      };
+      // Return the reading configuration
+      return Ok(Config{ filename, solver, format, policy,
+        progress: matches.is_present("progress"),
+        weighted: matches.is_present("weighted"),
+        preprocess: matches.is_present("preprocess"),
+        weight_attr: matches.value_of("weight-attr").map(String::from),
+        dot: matches.value_of("dot").map(String::from),
+        dot_clique: matches.is_present("dot-clique"),
+        save: matches.is_present("save"),
+        output: matches.value_of("output").map(PathBuf::from),
+        output_dir: matches.value_of("output-dir").map(PathBuf::from),
+        overwrite,
+        time_limit: time_limit(matches)?,
+        report: matches.value_of("report").map(String::from),
+        report_format })
+    }
+    Err("you did not enter the filename")
+  }
+
+  /// Returns the filename.
+  pub fn filename(&self) -> &String {
+    &self.filename
+  }
+
+  /// Returns the solver.
+  pub fn solver(&self) -> &Solver {
+    &self.solver
+  }
+
+  /// Returns the file format, if it was given.
+  pub fn format(&self) -> Option<Format> {
+    self.format
+  }
+
+  /// Returns the parsing policy.
+  pub fn policy(&self) -> Policy {
+    self.policy
+  }
+
+  /// Returns true if the reading progress must be reported or false
+  /// otherwise.
+  pub fn is_progress(&self) -> bool {
+    self.progress
+  }
+
+  /// Returns true if the weighted solver must be used or false otherwise.
+  pub fn is_weighted(&self) -> bool {
+    self.weighted
+  }
+
+  /// Returns true if the graph must be shrunk before the search or false
+  /// otherwise.
+  pub fn is_preprocess(&self) -> bool {
+    self.preprocess
+  }
+
+  /// Returns the name of the node attribute read as weight, if it was given.
+  pub fn weight_attr(&self) -> Option<&str> {
+    self.weight_attr.as_deref()
+  }
+
+  /// Returns the DOT output filename, if it was given.
+  pub fn dot(&self) -> Option<&str> {
+    self.dot.as_deref()
+  }
+
+  /// Returns true if only the clique subgraph must be written in the DOT
+  /// output or false otherwise.
+  pub fn is_dot_clique(&self) -> bool {
+    self.dot_clique
+  }
+
+  /// Returns true if the result must be saved or false otherwise, which is
+  /// implied by an output path or directory.
+  pub fn is_save(&self) -> bool {
+    self.save || self.output.is_some() || self.output_dir.is_some()
+  }
+
+  /// Returns the result file path, if it was given.
+  pub fn output(&self) -> Option<&Path> {
+    self.output.as_deref()
+  }
+
+  /// Returns the result file directory, if it was given.
+  pub fn output_dir(&self) -> Option<&Path> {
+    self.output_dir.as_deref()
+  }
+
+  /// Returns the overwrite policy of the result file.
+  pub fn overwrite(&self) -> Overwrite {
+    self.overwrite
+  }
+
+  /// Returns the solver time limit, if it was given.
+  pub fn time_limit(&self) -> Option<Duration> {
+    self.time_limit
+  }
+
+  /// Returns the result report filename, if it was given.
+  pub fn report(&self) -> Option<&str> {
+    self.report.as_deref()
+  }
+
+  /// Returns the result report format, if it was given.
+  pub fn report_format(&self) -> Option<ReportFormat> {
+    self.report_format
+  }
+}
+
+/// Conversion configuration.
+pub struct ConvertConfig {
+  input: String,
+  output: String,
+  from: Option<Format>,
+  to: Option<Format>,
+  weight_attr: Option<String>,
+  progress: bool,
+}
+
+impl ConvertConfig {
+  /// Validates the arguments and returns the conversion configuration.
+  pub fn new(matches: &ArgMatches) -> Result<ConvertConfig, &'static str> {
+    // Get input and output filenames from arguments
+    let input = matches.value_of("input")
+      .ok_or("you did not enter the input filename")?.to_string();
+    let output = matches.value_of("output")
+      .ok_or("you did not enter the output filename")?.to_string();
+    // Get the file formats
+    let from = match matches.value_of("from") {
+      Some(format) => Some(format.parse()?),
+      None => None,
+    };
+    let to = match matches.value_of("to") {
+      Some(format) => Some(format.parse()?),
+      None => None,
+    };
+    // Return the conversion configuration
+    Ok(ConvertConfig { input, output, from, to,
+      weight_attr: matches.value_of("weight-attr").map(String::from),
+      progress: matches.is_present("progress") })
+  }
+
+  /// Returns the input filename.
+  pub fn input(&self) -> &str {
+    &self.input
+  }
+
+  /// Returns the output filename.
+  pub fn output(&self) -> &str {
+    &self.output
+  }
+
+  /// Returns the input file format, if it was given.
+  pub fn from(&self) -> Option<Format> {
+    self.from
+  }
+
+  /// Returns the output file format, if it was given.
+  pub fn to(&self) -> Option<Format> {
+    self.to
+  }
+
+  /// Returns the name of the node attribute read as weight, if it was given.
+  pub fn weight_attr(&self) -> Option<&str> {
+    self.weight_attr.as_deref()
+  }
+
+  /// Returns true if the reading progress must be reported or false
+  /// otherwise.
+  pub fn is_progress(&self) -> bool {
+    self.progress
+  }
+}
+
+/// Verification configuration.
+pub struct VerifyConfig {
+  filename: String,
+  solution: String,
+  format: Option<Format>,
+  policy: Policy,
+  optimum: Option<usize>,
+  expected: Option<String>,
+}
+
+impl VerifyConfig {
+  /// Validates the arguments and returns the verification configuration.
+  pub fn new(matches: &ArgMatches) -> Result<VerifyConfig, &'static str> {
+    // Get the graph and solution filenames from arguments
+    let filename = matches.value_of("filename")
+      .ok_or("you did not enter the graph filename")?.to_string();
+    let solution = matches.value_of("solution")
+      .ok_or("you did not enter the solution filename")?.to_string();
+    // Get the file format
+    let format = match matches.value_of("format") {
+      Some(format) => Some(format.parse()?),
+      None => None,
+    };
+    // Get the parsing policy
+    let policy = if matches.is_present("lenient") { Policy::Lenient }
+      else { Policy::Strict };
+    // Get the known optimum
+    let optimum = match matches.value_of("optimum") {
+      Some(optimum) => Some(optimum.parse()
+        .map_err(|_| "the optimum must be a number of nodes")?),
+      None => None,
+    };
+    // Return the verification configuration
+    Ok(VerifyConfig { filename, solution, format, policy, optimum,
+      expected: matches.value_of("expected").map(String::from) })
+  }
+
+  /// Returns the graph filename.
+  pub fn filename(&self) -> &str {
+    &self.filename
+  }
+
+  /// Returns the solution filename.
+  pub fn solution(&self) -> &str {
+    &self.solution
+  }
+
+  /// Returns the graph file format, if it was given.
+  pub fn format(&self) -> Option<Format> {
+    self.format
+  }
+
+  /// Returns the parsing policy.
+  pub fn policy(&self) -> Policy {
+    self.policy
+  }
+
+  /// Returns the known maximum clique size, if it was given.
+  pub fn optimum(&self) -> Option<usize> {
+    self.optimum
+  }
+
+  /// Returns the expected results filename, if it was given.
+  pub fn expected(&self) -> Option<&str> {
+    self.expected.as_deref()
+  }
+}
+
+/// Benchmark configuration.
+pub struct BenchConfig {
+  instances: Vec<String>,
+  solvers: Vec<Solver>,
+  time_limit: Option<Duration>,
+  repetitions: usize,
+  report: Option<String>,
+  report_format: Option<ReportFormat>,
+}
+
+impl BenchConfig {
+  /// Validates the arguments and returns the benchmark configuration.
+  pub fn new(matches: &ArgMatches) -> Result<BenchConfig, &'static str> {
+    // Get the instance directories, files or patterns from arguments
+    let instances: Vec<String> = matches.values_of("instances")
+      .ok_or("you did not enter the instances")?.map(String::from).collect();
+    // Get the solvers, which default to all of them
+    let solvers = match matches.values_of("solver") {
+      Some(solvers) => solvers.map(str::parse).collect::<Result<_, _>>()?,
+      None => Solver::ALL.to_vec(),
+    };
+    // Get the number of repetitions
+    let repetitions = match matches.value_of("repetitions") {
+      Some(repetitions) => match repetitions.parse() {
+        Ok(repetitions) if repetitions > 0 => repetitions,
+        _ => return Err("the repetitions must be a positive number"),
+      },
+      None => 1,
+    };
+    // Get the report format
+    let report_format = match matches.value_of("report-format") {
+      Some(format) => Some(format.parse()?),
+      None => None,
+    };
+    // Return the benchmark configuration
+    Ok(BenchConfig { instances, solvers, time_limit: time_limit(matches)?,
+      repetitions, report: matches.value_of("report").map(String::from),
+      report_format })
+  }
+
+  /// Returns the instance directories, files or glob patterns.
+  pub fn instances(&self) -> &[String] {
+    &self.instances
+  }
+
+  /// Returns the solvers to compare.
+  pub fn solvers(&self) -> &[Solver] {
+    &self.solvers
+  }
+
+  /// Returns the time limit of each solver run, if it was given.
+  pub fn time_limit(&self) -> Option<Duration> {
+    self.time_limit
+  }
+
+  /// Returns the number of runs of each solver over each instance.
+  pub fn repetitions(&self) -> usize {
+    self.repetitions
+  }
+
+  /// Returns the report filename, if it was given.
+  pub fn report(&self) -> Option<&str> {
+    self.report.as_deref()
+  }
+
+  /// Returns the report format, if it was given.
+  pub fn report_format(&self) -> Option<ReportFormat> {
+    self.report_format
+  }
+}
+
+/// Generation configuration.
+pub struct GenerateConfig {
+  model: Model,
+  seed: u64,
+  output: String,
+  expected: Option<String>,
+  time_limit: Duration,
+}
+
+/// Parses the value of an argument, which is required by the model.
+fn parameter<T: FromStr>(matches: &ArgMatches, name: &str,
+  error: &'static str) -> Result<T, &'static str> {
+    matches.value_of(name).ok_or(error)?.parse().map_err(|_| error)
+}
+
+impl GenerateConfig {
+  /// Validates the arguments and returns the generation configuration.
+  pub fn new(matches: &ArgMatches) -> Result<GenerateConfig, &'static str> {
+    // Get the output filename from arguments
+    let output = matches.value_of("output")
+      .ok_or("you did not enter the output filename")?.to_string();
+    // Get the model parameters
+    let nodes = || parameter(matches, "nodes",
+      "the model needs a positive number of nodes");
+    let p = || parameter(matches, "density", "the model needs a density");
+    let clique = || parameter(matches, "clique",
+      "the model needs a clique size");
+    let degree = || parameter(matches, "degree", "the model needs a degree");
+    let length = || parameter(matches, "length",
+      "the model needs a word length");
+    let distance = || parameter(matches, "distance",
+      "the model needs a Hamming distance");
+    let dimension = || parameter(matches, "dimension",
+      "the model needs a dimension");
+    let model = match matches.value_of("model") {
+      Some("gnp") => Model::Gnp { nodes: nodes()?, p: p()? },
+      Some("gnm") => Model::Gnm { nodes: nodes()?,
+        edges: parameter(matches, "edges", "the model needs a number of edges")?
+      },
+      Some("planted") =>
+        Model::Planted { nodes: nodes()?, p: p()?, clique: clique()? },
+      Some("brock") =>
+        Model::Brockington { nodes: nodes()?, p: p()?, clique: clique()? },
+      Some("ba") => Model::BarabasiAlbert { nodes: nodes()?,
+        degree: degree()? },
+      Some("ws") => Model::WattsStrogatz { nodes: nodes()?, degree: degree()?,
+        beta: parameter(matches, "beta",
+          "the model needs a rewiring probability")? },
+      Some("geometric") => Model::Geometric { nodes: nodes()?,
+        radius: parameter(matches, "radius", "the model needs a radius")? },
+      Some("hamming") => Model::Hamming { length: length()?,
+        distance: distance()? },
+      Some("johnson") => Model::Johnson { length: length()?,
+        weight: parameter(matches, "weight", "the model needs a word weight")?,
+        distance: distance()? },
+      Some("keller") => Model::Keller { dimension: dimension()? },
+      Some("mann") => Model::Mann { points: parameter(matches, "points",
+        "the model needs a number of points")? },
+      _ => return Err("unknown model"),
+    };
+    // Get the seed
+    let seed = match matches.value_of("seed") {
+      Some(seed) => seed.parse().map_err(|_| "the seed must be a number")?,
+      None => 0,
+    };
+    // Return the generation configuration
+    Ok(GenerateConfig { model, seed, output,
+      expected: matches.value_of("expected").map(String::from),
+      time_limit: time_limit(matches)?.unwrap_or(Duration::from_secs(10)) })
+  }
+
+  /// Returns the graph model.
+  pub fn model(&self) -> &Model {
+    &self.model
+  }
+
+  /// Returns the seed of the random generator.
+  pub fn seed(&self) -> u64 {
+    self.seed
+  }
+
+  /// Returns the output filename.
+  pub fn output(&self) -> &str {
+    &self.output
+  }
+
+  /// Returns the expected results filename, if it was given.
+  pub fn expected(&self) -> Option<&str> {
+    self.expected.as_deref()
+  }
+
+  /// Returns the time limit of the search for a maximum clique when the
+  /// model does not know one.
+  pub fn time_limit(&self) -> Duration {
+    self.time_limit
+  }
+}
+
+/// Coloring configuration.
+pub struct ColorConfig {
+  filename: String,
+  algorithms: Vec<Algorithm>,
+  format: Option<Format>,
+  policy: Policy,
+  classes: bool,
+  exact: bool,
+  time_limit: Option<Duration>,
+}
+
+impl ColorConfig {
+  /// Validates the arguments and returns the coloring configuration.
+  pub fn new(matches: &ArgMatches) -> Result<ColorConfig, &'static str> {
+    // Get the graph filename from arguments
+    let filename = matches.value_of("filename")
+      .ok_or("you did not enter the graph filename")?.to_string();
+    // Get the algorithms, which default to all of them
+    let algorithms = match matches.values_of("algorithm") {
+      Some(algorithms) => algorithms.map(str::parse)
+        .collect::<Result<_, _>>()?,
+      None => Algorithm::ALL.to_vec(),
+    };
+    // Get the file format
+    let format = match matches.value_of("format") {
+      Some(format) => Some(format.parse()?),
+      None => None,
+    };
+    // Get the parsing policy
+    let policy = if matches.is_present("lenient") { Policy::Lenient }
+      else { Policy::Strict };
+    // Return the coloring configuration
+    Ok(ColorConfig { filename, algorithms, format, policy,
+      classes: matches.is_present("classes"),
+      exact: matches.is_present("exact"),
+      time_limit: time_limit(matches)? })
+  }
+
+  /// Returns the graph filename.
+  pub fn filename(&self) -> &str {
+    &self.filename
+  }
+
+  /// Returns the coloring algorithms to run.
+  pub fn algorithms(&self) -> &[Algorithm] {
+    &self.algorithms
+  }
+
+  /// Returns the graph file format, if it was given.
+  pub fn format(&self) -> Option<Format> {
+    self.format
+  }
+
+  /// Returns the parsing policy.
+  pub fn policy(&self) -> Policy {
+    self.policy
+  }
+
+  /// Returns true if the nodes of each color must be printed or false
+  /// otherwise.
+  pub fn is_classes(&self) -> bool {
+    self.classes
+  }
+
+  /// Returns true if the chromatic number must be found or false otherwise.
+  pub fn is_exact(&self) -> bool {
+    self.exact
+  }
+
+  /// Returns the time limit of the exact search, if it was given.
+  pub fn time_limit(&self) -> Option<Duration> {
+    self.time_limit
+  }
+}
+
+/// Bounds configuration.
+pub struct BoundsConfig {
+  filename: String,
+  format: Option<Format>,
+  policy: Policy,
+  iterations: usize,
+}
+
+impl BoundsConfig {
+  /// Validates the arguments and returns the bounds configuration.
+  pub fn new(matches: &ArgMatches) -> Result<BoundsConfig, &'static str> {
+    // Get the graph filename from arguments
+    let filename = matches.value_of("filename")
+      .ok_or("you did not enter the graph filename")?.to_string();
+    // Get the file format
+    let format = match matches.value_of("format") {
+      Some(format) => Some(format.parse()?),
+      None => None,
+    };
+    // Get the parsing policy
+    let policy = if matches.is_present("lenient") { Policy::Lenient }
+      else { Policy::Strict };
+    // Get the number of iterations
+    let iterations = match matches.value_of("iterations") {
+      Some(iterations) => iterations.parse()
+        .map_err(|_| "the iterations must be a number")?,
+      None => bounds::ITERATIONS,
+    };
+    // Return the bounds configuration
+    Ok(BoundsConfig { filename, format, policy, iterations })
+  }
+
+  /// Returns the graph filename.
+  pub fn filename(&self) -> &str {
+    &self.filename
+  }
+
+  /// Returns the graph file format, if it was given.
+  pub fn format(&self) -> Option<Format> {
+    self.format
+  }
+
+  /// Returns the parsing policy.
+  pub fn policy(&self) -> Policy {
+    self.policy
+  }
+
+  /// Returns the number of iterations of the Lovász theta computation.
+  pub fn iterations(&self) -> usize {
+    self.iterations
+  }
+}
@@ -0,0 +1,143 @@
+use std::cmp;
+use std::io::{BufRead, Write};
+use crate::error::Error;
+use crate::graph::Graph;
+use super::{EdgeSet, Instance, Policy};
+
+/// Maximum number of tokens kept from a line.
+pub(super) const MAX_TOKENS: usize = 5;
+
+/// Splits a line into whitespace separated tokens paired with their columns
+/// and returns the total number of tokens. Only the first `MAX_TOKENS` tokens
+/// are kept, so that no allocation is performed per line.
+pub(super) fn tokens<'a>(line: &'a str,
+  tks: &mut [(usize, &'a str); MAX_TOKENS]) -> usize {
+    let mut len = 0;
+    for token in line.split_whitespace() {
+      let column = token.as_ptr() as usize - line.as_ptr() as usize + 1;
+      if len < MAX_TOKENS { tks[len] = (column, token); }
+      len += 1;
+    }
+    len
+}
+
+/// Parses a token as a number or returns an error pointing to it.
+pub(super) fn number(ln: usize, (column, token): (usize, &str))
+  -> Result<usize, Error> {
+    token.parse::<usize>().map_err(|_|
+      Error::parse(ln, column, format!("invalid number `{}`", token)))
+}
+
+/// Parses the tokens of a `p <format> <nodes> <edges>` line and returns the
+/// number of nodes and edges.
+pub(super) fn problem(ln: usize, tks: &[(usize, &str)])
+  -> Result<(usize, usize), Error> {
+    if tks.len() != 4 {
+      return Err(Error::parse(ln, tks[0].0,
+        "expected `p <format> <nodes> <edges>`"))
+    }
+    if tks[1].1 != "col" && tks[1].1 != "edge" {
+      return Err(Error::parse(ln, tks[1].0,
+        format!("unsupported format `{}`", tks[1].1)))
+    }
+    let nlen = number(ln, tks[2])?;
+    super::check_nodes(ln, tks[2].0, nlen)?;
+    Ok((nlen, number(ln, tks[3])?))
+}
+
+/// Parses the contents of a DIMACS `.clq` file and returns the respective
+/// graph with the diagnostics collected while parsing.
+pub fn parse(content: &str, policy: Policy) -> Result<Instance, Error> {
+  read(content.as_bytes(), policy)
+}
+
+/// Reads a DIMACS `.clq` file line by line, building the graph incrementally,
+/// and returns the respective graph with the diagnostics collected while
+/// parsing.
+///
+/// The file must contain exactly one `p col <nodes> <edges>` (or `p edge`)
+/// line before any `e <a> <b>` line. Self-loops, duplicate edges, unknown
+/// line types and a wrong number of edges are errors under the strict policy
+/// and are skipped with a diagnostic under the lenient one.
+pub fn read<R: BufRead>(mut reader: R, policy: Policy)
+  -> Result<Instance, Error> {
+    let mut graph: Option<Graph> = None;
+    let mut diagnostics = vec![];
+    let mut edges = EdgeSet::default();
+    // Expected and read number of edges and the problem line number
+    let (mut elen, mut count, mut pln) = (0, 0, 0);
+    // Reports a problem according to the policy
+    let mut report = |ln: usize, column: usize, message: String|
+      policy.report(&mut diagnostics, ln, column, message);
+    let mut line = String::new();
+    let mut ln = 0;
+    loop {
+      // Read the next line reusing the line buffer
+      line.clear();
+      if reader.read_line(&mut line)? == 0 { break }
+      ln += 1;
+      let mut tks = [(0, ""); MAX_TOKENS];
+      let len = tokens(&line, &mut tks);
+      // Ignore empty lines and comments
+      if len == 0 || tks[0].1 == "c" { continue; }
+      match tks[0].1 {
+        "p" => {
+          // Check if the problem line is unique
+          if graph.is_some() {
+            return Err(Error::parse(ln, tks[0].0, "duplicate problem line"))
+          }
+          let (nlen, m) = problem(ln, &tks[..cmp::min(len, MAX_TOKENS)])?;
+          elen = m;
+          pln = ln;
+          graph = Some(Graph::new(nlen));
+        },
+        "e" => {
+          let graph = match graph.as_mut() {
+            Some(graph) => graph,
+            None => return Err(Error::parse(ln, tks[0].0,
+              "edge found before the problem line")),
+          };
+          if len != 3 {
+            return Err(Error::parse(ln, tks[0].0, "expected `e <a> <b>`"))
+          }
+          let (a, b) = (number(ln, tks[1])?, number(ln, tks[2])?);
+          count += 1;
+          // Check if the nodes belong to the graph
+          for &(n, (column, _)) in &[(a, tks[1]), (b, tks[2])] {
+            if !graph.contains_node(n) {
+              return Err(Error::parse(ln, column,
+                format!("the node {} is out of range 1..={}", n,
+                  graph.nlen())))
+            }
+          }
+          // Check for self-loops and duplicate edges
+          if a == b {
+            report(ln, tks[1].0, format!("self-loop on node {}", a))?;
+          } else if !edges.insert((a, b)) {
+            report(ln, tks[1].0, format!("duplicate edge ({}, {})", a, b))?;
+          } else {
+            graph.try_insert_edge((a, b))?;
+          }
+        },
+        t => report(ln, tks[0].0, format!("unknown line type `{}`", t))?,
+      }
+    }
+    let graph = match graph {
+      Some(graph) => graph,
+      None => return Err(Error::parse(1, 1, "missing problem line")),
+    };
+    // Check if the graph has the right number of edges
+    if count != elen {
+      report(pln, 1, format!("expected {} edges but found {}", elen, count))?;
+    }
+    Ok(Instance { graph, ids: None, weights: None, diagnostics })
+}
+
+/// Writes a graph in the DIMACS `.clq` format. The nodes are renumbered from
+/// one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph) -> Result<(), Error> {
+  let edges = super::normalized_edges(graph);
+  writeln!(writer, "p col {} {}", graph.nlen(), edges.len())?;
+  for (a, b) in edges { writeln!(writer, "e {} {}", a, b)?; }
+  Ok(())
+}
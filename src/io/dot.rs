@@ -0,0 +1,66 @@
+use std::io::Write;
+use crate::error::Error;
+use crate::graph::Graph;
+use super::label;
+
+/// Colour of the clique nodes and edges.
+const HIGHLIGHT: &str = "#d62728";
+
+/// Returns a node id quoted as a DOT string.
+fn quote(id: &str) -> String {
+  format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the edges of a graph from its nodes in ascending order, keeping
+/// the original node numbers.
+fn edges(graph: &Graph) -> Vec<(usize, usize)> {
+  let mut nodes = graph.nodes(); nodes.sort();
+  nodes.into_iter().flat_map(|n| graph.adjlst_of(n).iter()
+    .filter(move |&&an| an > n).map(move |&an| (n, an))).collect()
+}
+
+/// Writes a graph in the Graphviz DOT format with the nodes and edges of a
+/// clique highlighted. The nodes are named by their original ids, if the
+/// graph file has them.
+pub fn write<W: Write>(mut writer: W, graph: &Graph, clique: &Graph,
+  ids: Option<&[String]>) -> Result<(), Error> {
+    writeln!(writer, "graph G {{")?;
+    writeln!(writer, "  node [shape=circle];")?;
+    let mut nodes = graph.nodes(); nodes.sort();
+    for &n in &nodes {
+      let id = quote(&label(ids, n));
+      if clique.contains_node(n) {
+        writeln!(writer, "  {} [style=filled, fillcolor=\"{}\", \
+          fontcolor=white];", id, HIGHLIGHT)?;
+      } else {
+        writeln!(writer, "  {};", id)?;
+      }
+    }
+    for (a, b) in edges(graph) {
+      let (ia, ib) = (quote(&label(ids, a)), quote(&label(ids, b)));
+      if clique.contains_edge((a, b)) {
+        writeln!(writer, "  {} -- {} [color=\"{}\", penwidth=2.5, \
+          style=bold];", ia, ib, HIGHLIGHT)?;
+      } else {
+        writeln!(writer, "  {} -- {} [color=gray];", ia, ib)?;
+      }
+    }
+    writeln!(writer, "}}")?;
+    Ok(())
+}
+
+/// Writes only the subgraph induced by a clique in the Graphviz DOT format.
+/// The nodes are named by their original ids, if the graph file has them.
+pub fn write_clique<W: Write>(mut writer: W, clique: &Graph,
+  ids: Option<&[String]>) -> Result<(), Error> {
+    writeln!(writer, "graph G {{")?;
+    writeln!(writer, "  node [shape=circle];")?;
+    let mut nodes = clique.nodes(); nodes.sort();
+    for &n in &nodes { writeln!(writer, "  {};", quote(&label(ids, n)))?; }
+    for (a, b) in edges(clique) {
+      writeln!(writer, "  {} -- {};", quote(&label(ids, a)),
+        quote(&label(ids, b)))?;
+    }
+    writeln!(writer, "}}")?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::io::{BufRead, Write};
+use crate::error::Error;
+use crate::graph::Graph;
+use super::dimacs::{self, MAX_TOKENS};
+use super::{EdgeSet, IdMap, Instance, Policy};
+
+/// Reads a whitespace separated edge list, such as the SNAP datasets, and
+/// returns the respective graph.
+///
+/// Each line holds the ids of the two nodes of an edge, which may be any
+/// string, and further columns are ignored. Lines starting with `#` or `%`
+/// are comments. The nodes are numbered from one in order of appearance and
+/// their ids are kept in the instance. The edges listed in both directions,
+/// as in many SNAP datasets, are merged silently, while self-loops and edges
+/// listed twice in the same direction are handled according to the policy.
+pub fn read<R: BufRead>(mut reader: R, policy: Policy)
+  -> Result<Instance, Error> {
+    let mut graph = Graph::default();
+    let mut ids = IdMap::default();
+    let mut diagnostics = vec![];
+    let mut edges = EdgeSet::default();
+    let mut line = String::new();
+    let mut ln = 0;
+    loop {
+      // Read the next line reusing the line buffer
+      line.clear();
+      if reader.read_line(&mut line)? == 0 { break }
+      ln += 1;
+      let mut tks = [(0, ""); MAX_TOKENS];
+      let len = dimacs::tokens(&line, &mut tks);
+      // Ignore empty lines and comments
+      if len == 0 || tks[0].1.starts_with('#') || tks[0].1.starts_with('%') {
+        continue;
+      }
+      if len < 2 {
+        return Err(Error::parse(ln, tks[0].0, "expected `<a> <b>`"))
+      }
+      let a = ids.insert(tks[0].1, &mut graph);
+      let b = ids.insert(tks[1].1, &mut graph);
+      // Check for self-loops and duplicate edges
+      if a == b {
+        policy.report(&mut diagnostics, ln, tks[0].0,
+          format!("self-loop on node {}", tks[0].1))?;
+      } else {
+        let (edge, arc) = edges.insert_arc((a, b));
+        if !arc {
+          policy.report(&mut diagnostics, ln, tks[0].0,
+            format!("duplicate edge ({}, {})", tks[0].1, tks[1].1))?;
+        } else if edge {
+          graph.try_insert_edge((a, b))?;
+        }
+      }
+    }
+    if graph.is_empty() { return Err(Error::EmptyGraph) }
+    Ok(Instance { graph, ids: Some(ids.into_ids()), weights: None,
+      diagnostics })
+}
+
+/// Writes a graph as a whitespace separated edge list. The nodes are
+/// renumbered from one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph) -> Result<(), Error> {
+  for (a, b) in super::normalized_edges(graph) {
+    writeln!(writer, "{} {}", a, b)?;
+  }
+  Ok(())
+}
@@ -0,0 +1,141 @@
+use std::io::{Read, Write};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use crate::error::Error;
+use crate::graph::Graph;
+use super::xml::{self, Builder};
+use super::{Instance, Policy};
+
+/// Reads a GEXF file and returns the respective graph.
+///
+/// The nodes are numbered from one in order of appearance and their ids are
+/// kept in the instance. If a weight attribute is given, the values of the
+/// node attribute with that title (or id) are kept as weights, and the nodes
+/// without them get the default value of the attribute. Repeated edges are
+/// merged silently in directed graphs, while self-loops and duplicate edges
+/// of undirected graphs are handled according to the policy.
+pub fn read<R: Read>(mut reader: R, policy: Policy, weight: Option<&str>)
+  -> Result<Instance, Error> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+    let mut reader = Reader::from_str(&content);
+    let mut builder = Builder::default();
+    // Id and default value of the weight attribute
+    let mut attr: Option<String> = None;
+    let mut default = None;
+    // Whether the text being read is the weight attribute default
+    let (mut in_nodes_attrs, mut in_attr, mut in_default) =
+      (false, false, false);
+    let mut node = None;
+    let mut text = String::new();
+    let mut directed = false;
+    loop {
+      let event = reader.read_event()
+        .map_err(|e| xml::xml_error(&content, &reader, e))?;
+      match event {
+        Event::Start(ref e) | Event::Empty(ref e) => {
+          let empty = matches!(event, Event::Empty(_));
+          match e.local_name().as_ref() {
+            b"attributes" => {
+              in_nodes_attrs = xml::attribute(e, "class").as_deref()
+                == Some("node") && !empty;
+            },
+            b"attribute" if in_nodes_attrs => {
+              let id = xml::required(&content, &reader, e, "id")?;
+              let title = xml::attribute(e, "title");
+              if weight.is_some_and(|w|
+                title.as_deref() == Some(w) || (title.is_none() && id == w)) {
+                  attr = Some(id);
+                  in_attr = !empty;
+              }
+            },
+            b"default" if in_attr => { in_default = true; text.clear(); },
+            b"graph" => {
+              directed = xml::attribute(e, "defaultedgetype").as_deref()
+                == Some("directed");
+            },
+            b"node" => {
+              let id = xml::required(&content, &reader, e, "id")?;
+              let n = builder.node(&id);
+              if !empty { node = Some(n); }
+            },
+            b"attvalue" if node.is_some() => {
+              // GEXF 1.0 identifies the attribute by `id` instead of `for`
+              let id = xml::attribute(e, "for").or_else(||
+                xml::attribute(e, "id"));
+              if id.is_some() && id == attr {
+                let value = xml::required(&content, &reader, e, "value")?;
+                let w = xml::weight(&content, &reader, &value)?;
+                if let Some(n) = node { builder.weight(n, w); }
+              }
+            },
+            b"edge" => {
+              let pos = (content.as_str(), reader.buffer_position() as usize);
+              let source = xml::required(&content, &reader, e, "source")?;
+              let target = xml::required(&content, &reader, e, "target")?;
+              builder.edge((&source, &target), directed, policy, pos)?;
+            },
+            _ => (),
+          }
+        },
+        Event::Text(ref e) if in_default => {
+          let t = e.decode().map_err(|e|
+            xml::xml_error(&content, &reader, e.into()))?;
+          text.push_str(&t);
+        },
+        Event::End(ref e) => match e.local_name().as_ref() {
+          b"attributes" => in_nodes_attrs = false,
+          b"attribute" => in_attr = false,
+          b"default" if in_default => {
+            default = Some(xml::weight(&content, &reader, &text)?);
+            in_default = false;
+          },
+          b"node" => node = None,
+          _ => (),
+        },
+        Event::Eof => break,
+        _ => (),
+      }
+    }
+    // Check if the weight attribute exists
+    if let (Some(w), None) = (weight, &attr) {
+      return Err(Error::parse(1, 1, format!("unknown node attribute `{}`", w)))
+    }
+    builder.finish(weight.is_some(), default, policy)
+}
+
+/// Writes a graph in the GEXF format with the given node weights as the
+/// `weight` attribute. The nodes are renumbered from one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph, weights: Option<&[f64]>)
+  -> Result<(), Error> {
+    writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(writer,
+      "<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">")?;
+    writeln!(writer, "  <graph defaultedgetype=\"undirected\">")?;
+    if weights.is_some() {
+      writeln!(writer, "    <attributes class=\"node\">")?;
+      writeln!(writer, "      <attribute id=\"weight\" title=\"weight\" \
+        type=\"double\"/>")?;
+      writeln!(writer, "    </attributes>")?;
+    }
+    writeln!(writer, "    <nodes>")?;
+    let mut nodes = graph.nodes(); nodes.sort();
+    for (i, n) in nodes.iter().enumerate() {
+      match weights.and_then(|w| n.checked_sub(1).and_then(|i| w.get(i))) {
+        Some(w) => writeln!(writer, "      <node id=\"{}\"><attvalues>\
+          <attvalue for=\"weight\" value=\"{}\"/></attvalues></node>",
+          i + 1, w)?,
+        None => writeln!(writer, "      <node id=\"{}\"/>", i + 1)?,
+      }
+    }
+    writeln!(writer, "    </nodes>")?;
+    writeln!(writer, "    <edges>")?;
+    for (i, (a, b)) in super::normalized_edges(graph).iter().enumerate() {
+      writeln!(writer, "      <edge id=\"{}\" source=\"{}\" target=\"{}\"/>",
+        i, a, b)?;
+    }
+    writeln!(writer, "    </edges>")?;
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</gexf>")?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::io::{Read, Write};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use crate::error::Error;
+use crate::graph::Graph;
+use super::xml::{self, Builder};
+use super::{Instance, Policy};
+
+/// Reads a GraphML file and returns the respective graph.
+///
+/// The nodes are numbered from one in order of appearance and their ids are
+/// kept in the instance. If a weight attribute is given, the values of the
+/// node data with the key of that name (or id) are kept as weights, and the
+/// nodes without them get the default value of the key. Repeated edges are
+/// merged silently in directed graphs, while self-loops and duplicate edges
+/// of undirected graphs are handled according to the policy.
+pub fn read<R: Read>(mut reader: R, policy: Policy, weight: Option<&str>)
+  -> Result<Instance, Error> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+    let mut reader = Reader::from_str(&content);
+    let mut builder = Builder::default();
+    // Id and default value of the weight key
+    let mut key: Option<String> = None;
+    let mut default = None;
+    // Whether the text being read is the weight key default or node data
+    let (mut in_key, mut in_default, mut in_data) = (false, false, false);
+    let mut node = None;
+    let mut text = String::new();
+    let mut directed = false;
+    loop {
+      let event = reader.read_event()
+        .map_err(|e| xml::xml_error(&content, &reader, e))?;
+      match event {
+        Event::Start(ref e) | Event::Empty(ref e) => {
+          let empty = matches!(event, Event::Empty(_));
+          match e.local_name().as_ref() {
+            b"key" => {
+              let id = xml::required(&content, &reader, e, "id")?;
+              let name = xml::attribute(e, "attr.name");
+              let target = xml::attribute(e, "for");
+              let matches = weight.is_some_and(|w|
+                name.as_deref() == Some(w) || (name.is_none() && id == w));
+              if matches && target.as_deref() != Some("edge") {
+                key = Some(id);
+                in_key = !empty;
+              }
+            },
+            b"default" if in_key => { in_default = true; text.clear(); },
+            b"graph" => {
+              directed = xml::attribute(e, "edgedefault").as_deref()
+                == Some("directed");
+            },
+            b"node" => {
+              let id = xml::required(&content, &reader, e, "id")?;
+              let n = builder.node(&id);
+              if !empty { node = Some(n); }
+            },
+            b"data" if node.is_some() => {
+              let k = xml::required(&content, &reader, e, "key")?;
+              in_data = key.as_deref() == Some(k.as_str()) && !empty;
+              text.clear();
+            },
+            b"edge" => {
+              let pos = (content.as_str(), reader.buffer_position() as usize);
+              let source = xml::required(&content, &reader, e, "source")?;
+              let target = xml::required(&content, &reader, e, "target")?;
+              builder.edge((&source, &target), directed, policy, pos)?;
+            },
+            _ => (),
+          }
+        },
+        Event::Text(ref e) if in_default || in_data => {
+          let t = e.decode().map_err(|e|
+            xml::xml_error(&content, &reader, e.into()))?;
+          text.push_str(&t);
+        },
+        Event::End(ref e) => match e.local_name().as_ref() {
+          b"key" => in_key = false,
+          b"default" if in_default => {
+            default = Some(xml::weight(&content, &reader, &text)?);
+            in_default = false;
+          },
+          b"data" if in_data => {
+            let w = xml::weight(&content, &reader, &text)?;
+            if let Some(n) = node { builder.weight(n, w); }
+            in_data = false;
+          },
+          b"node" => node = None,
+          _ => (),
+        },
+        Event::Eof => break,
+        _ => (),
+      }
+    }
+    // Check if the weight attribute exists
+    if let (Some(w), None) = (weight, &key) {
+      return Err(Error::parse(1, 1, format!("unknown node attribute `{}`", w)))
+    }
+    builder.finish(weight.is_some(), default, policy)
+}
+
+/// Writes a graph in the GraphML format with the given node weights as the
+/// `weight` attribute. The nodes are renumbered from one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph, weights: Option<&[f64]>)
+  -> Result<(), Error> {
+    writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(writer,
+      "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">")?;
+    if weights.is_some() {
+      writeln!(writer, "  <key id=\"weight\" for=\"node\" \
+        attr.name=\"weight\" attr.type=\"double\"/>")?;
+    }
+    writeln!(writer, "  <graph id=\"G\" edgedefault=\"undirected\">")?;
+    let mut nodes = graph.nodes(); nodes.sort();
+    for (i, n) in nodes.iter().enumerate() {
+      match weights.and_then(|w| n.checked_sub(1).and_then(|i| w.get(i))) {
+        Some(w) => writeln!(writer, "    <node id=\"{}\"><data key=\"weight\">\
+          {}</data></node>", i + 1, w)?,
+        None => writeln!(writer, "    <node id=\"{}\"/>", i + 1)?,
+      }
+    }
+    for (a, b) in super::normalized_edges(graph) {
+      writeln!(writer, "    <edge source=\"{}\" target=\"{}\"/>", a, b)?;
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+    Ok(())
+}
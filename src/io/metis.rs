@@ -0,0 +1,173 @@
+use std::io::{BufRead, Write};
+use crate::error::Error;
+use crate::graph::Graph;
+use super::dimacs::number;
+use super::{EdgeSet, Instance, Policy};
+
+/// Parses a token as a weight or returns an error pointing to it.
+fn weight(ln: usize, (column, token): (usize, &str)) -> Result<f64, Error> {
+  match token.parse::<f64>() {
+    Ok(w) if w.is_finite() => Ok(w),
+    _ => Err(Error::parse(ln, column,
+      format!("invalid weight `{}`", token))),
+  }
+}
+
+/// Returns the tokens of a line paired with their columns.
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+  line.split_whitespace()
+    .map(|t| (t.as_ptr() as usize - line.as_ptr() as usize + 1, t))
+    .collect()
+}
+
+/// Reads the next line that is not a comment and returns false if there are
+/// no more lines.
+fn next_line<R: BufRead>(reader: &mut R, line: &mut String, ln: &mut usize)
+  -> Result<bool, Error> {
+    loop {
+      line.clear();
+      if reader.read_line(line)? == 0 { return Ok(false) }
+      *ln += 1;
+      if !line.starts_with('%') { return Ok(true) }
+    }
+}
+
+/// Reads a METIS `.graph` file and returns the respective graph.
+///
+/// The header `<nodes> <edges> [<fmt> [<ncon>]]` is followed by one line per
+/// node holding its optional size, its optional weights and its adjacent
+/// nodes, each one followed by the edge weight if the format has them. Only
+/// the first weight of each node is kept, and sizes and edge weights are
+/// ignored. Self-loops, duplicate and asymmetric edges and a wrong number of
+/// edges are handled according to the policy.
+pub fn read<R: BufRead>(mut reader: R, policy: Policy)
+  -> Result<Instance, Error> {
+    let mut diagnostics = vec![];
+    let mut edges = EdgeSet::default();
+    let mut line = String::new();
+    let mut ln = 0;
+    // Read the header
+    if !next_line(&mut reader, &mut line, &mut ln)? {
+      return Err(Error::parse(1, 1, "missing header"))
+    }
+    let tks = tokens(&line);
+    if tks.len() < 2 || tks.len() > 4 {
+      return Err(Error::parse(ln, 1,
+        "expected `<nodes> <edges> [<fmt> [<ncon>]]`"))
+    }
+    let (nlen, elen) = (number(ln, tks[0])?, number(ln, tks[1])?);
+    super::check_nodes(ln, tks[0].0, nlen)?;
+    let fmt = tks.get(2).map_or("0", |t| t.1);
+    if fmt.len() > 3 || !fmt.chars().all(|c| c == '0' || c == '1') {
+      return Err(Error::parse(ln, tks[2].0,
+        format!("invalid format `{}`", fmt)))
+    }
+    let fmt = format!("{:0>3}", fmt).into_bytes();
+    let (sizes, nweights, eweights) =
+      (fmt[0] == b'1', fmt[1] == b'1', fmt[2] == b'1');
+    let ncon = match tks.get(3) {
+      Some(&t) => number(ln, t)?,
+      None => if nweights { 1 } else { 0 },
+    };
+    if nweights && ncon == 0 {
+      return Err(Error::parse(ln, tks[3].0,
+        "the number of node weights cannot be zero"))
+    }
+    // Number of leading tokens of each adjacency line
+    let skip = usize::from(sizes).checked_add(if nweights { ncon } else { 0 })
+      .ok_or_else(|| Error::parse(ln, tks[3].0, "too many node weights"))?;
+    let hln = ln;
+    // Read the adjacency lines
+    let mut graph = Graph::new(nlen);
+    let mut weights = vec![];
+    // Number of adjacent nodes listed in the line of each node
+    let mut listed = vec![(0, 0); nlen];
+    for a in 1..=nlen {
+      if !next_line(&mut reader, &mut line, &mut ln)? {
+        return Err(Error::parse(ln + 1, 1,
+          format!("missing the adjacency line of node {}", a)))
+      }
+      let tks = tokens(&line);
+      if tks.len() < skip {
+        return Err(Error::parse(ln, 1, "missing node size or weights"))
+      }
+      if nweights { weights.push(weight(ln, tks[skip - ncon])?); }
+      let step = if eweights { 2 } else { 1 };
+      if !(tks.len() - skip).is_multiple_of(step) {
+        return Err(Error::parse(ln, tks[tks.len() - 1].0,
+          "missing edge weight"))
+      }
+      listed[a - 1] = (ln, (tks.len() - skip) / step);
+      for &(column, token) in tks[skip..].iter().step_by(step) {
+        let b = number(ln, (column, token))?;
+        if !graph.contains_node(b) {
+          return Err(Error::parse(ln, column,
+            format!("the node {} is out of range 1..={}", b, nlen)))
+        }
+        // Check for self-loops and duplicate edges
+        if a == b {
+          policy.report(&mut diagnostics, ln, column,
+            format!("self-loop on node {}", a))?;
+        } else if edges.insert((a, b)) {
+          graph.try_insert_edge((a, b))?;
+        } else if b > a {
+          policy.report(&mut diagnostics, ln, column,
+            format!("duplicate edge ({}, {})", a, b))?;
+        }
+      }
+    }
+    // Check for unexpected lines
+    while next_line(&mut reader, &mut line, &mut ln)? {
+      if !line.trim().is_empty() {
+        policy.report(&mut diagnostics, ln, 1,
+          "unexpected line after the adjacency lines".to_string())?;
+      }
+    }
+    // Check if the edges are listed by both of their nodes
+    for (i, &(ln, len)) in listed.iter().enumerate() {
+      if graph.degree_of(i + 1) != len {
+        policy.report(&mut diagnostics, ln, 1,
+          format!("the adjacency of node {} is not symmetric", i + 1))?;
+      }
+    }
+    // Check if the graph has the right number of edges
+    if graph.elen() != elen {
+      policy.report(&mut diagnostics, hln, 1,
+        format!("expected {} edges but found {}", elen, graph.elen()))?;
+    }
+    let weights = if nweights { Some(weights) } else { None };
+    Ok(Instance { graph, ids: None, weights, diagnostics })
+}
+
+/// Writes a graph in the METIS `.graph` format with the given node weights,
+/// which must be non-negative integers. The nodes are renumbered from one in
+/// ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph, weights: Option<&[f64]>)
+  -> Result<(), Error> {
+    let mut nodes = graph.nodes(); nodes.sort();
+    let index = super::index(&nodes);
+    // Write the header
+    write!(writer, "{} {}", graph.nlen(), graph.elen())?;
+    if weights.is_some() { write!(writer, " 010")?; }
+    writeln!(writer)?;
+    // Write the adjacency lines
+    for n in nodes {
+      let mut line = vec![];
+      if let Some(weights) = weights {
+        let w = n.checked_sub(1).and_then(|i| weights.get(i)).copied()
+          .unwrap_or(1.0);
+        if w < 0.0 || w.fract() != 0.0 {
+          return Err(Error::InvalidParameter(
+            "METIS node weights must be non-negative integers".to_string()))
+        }
+        line.push(w as u64);
+      }
+      let mut adjlst: Vec<u64> = graph.adjlst_of(n).iter()
+        .map(|an| index[an] as u64).collect();
+      adjlst.sort();
+      line.extend(adjlst);
+      let line: Vec<String> = line.iter().map(|n| n.to_string()).collect();
+      writeln!(writer, "{}", line.join(" "))?;
+    }
+    Ok(())
+}
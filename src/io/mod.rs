@@ -0,0 +1,683 @@
+extern crate bzip2;
+extern crate dirs;
+extern crate flate2;
+extern crate xz2;
+
+pub mod adjlist;
+pub mod binary;
+mod config;
+pub mod dimacs;
+pub mod dot;
+pub mod edgelist;
+pub mod gexf;
+pub mod graphml;
+pub mod metis;
+pub mod mtx;
+pub mod report;
+pub mod solution;
+mod xml;
+
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+#[cfg(feature = "mmap")]
+use std::io::Cursor;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use bzip2::read::MultiBzDecoder;
+use bzip2::write::BzEncoder;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use xz2::read::XzDecoder;
+use xz2::write::XzEncoder;
+
+pub use self::config::{BenchConfig, BoundsConfig, ColorConfig, Config};
+pub use self::config::{ConvertConfig, Solver};
+pub use self::config::{GenerateConfig, VerifyConfig};
+pub use self::report::{Report, ReportFormat};
+pub use self::solution::SolutionFile;
+use crate::error::Error;
+use crate::graph::Graph;
+
+/// Policies for handling malformed but recoverable graph file contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+  /// Rejects the file.
+  Strict,
+  /// Skips the offending content and reports a diagnostic.
+  Lenient,
+}
+
+impl Policy {
+  /// Reports a problem found at the given line and column: returns an error
+  /// under the strict policy or records a diagnostic under the lenient one.
+  pub(crate) fn report(self, diagnostics: &mut Vec<Diagnostic>, line: usize,
+    column: usize, message: String) -> Result<(), Error> {
+      match self {
+        Policy::Strict => Err(Error::parse(line, column, message)),
+        Policy::Lenient => {
+          diagnostics.push(Diagnostic { line, message });
+          Ok(())
+        },
+      }
+  }
+}
+
+/// A problem found and skipped while reading a graph file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+  pub line: usize,
+  pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "line {}: {}", self.line, self.message)
+  }
+}
+
+/// A graph read from a file.
+#[derive(Clone, Debug)]
+pub struct Instance {
+  /// Graph with nodes numbered from one.
+  pub graph: Graph,
+  /// Original ids of the nodes, where `ids[n - 1]` is the id of the node `n`,
+  /// if the file does not number the nodes from one.
+  pub ids: Option<Vec<String>>,
+  /// Weights of the nodes, where `weights[n - 1]` is the weight of the node
+  /// `n`, if the file has them.
+  pub weights: Option<Vec<f64>>,
+  /// Problems found and skipped while reading the file.
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Instance {
+  /// Returns the original id of a node.
+  pub fn id(&self, n: usize) -> String {
+    label(self.ids.as_deref(), n)
+  }
+}
+
+/// Returns the original id of a node given the ids of the graph file.
+fn label(ids: Option<&[String]>, n: usize) -> String {
+  match ids.and_then(|ids| n.checked_sub(1).and_then(|i| ids.get(i))) {
+    Some(id) => id.clone(),
+    None => n.to_string(),
+  }
+}
+
+/// Largest number of nodes that a graph file header can declare, which bounds
+/// the memory allocated before the edges are read to a few dozen megabytes.
+pub const MAX_NODES: usize = 1 << 20;
+
+/// Checks the number of nodes declared by a header at the given line and
+/// column.
+pub(crate) fn check_nodes(ln: usize, column: usize, nlen: usize)
+  -> Result<(), Error> {
+    if nlen == 0 { return Err(Error::EmptyGraph) }
+    if nlen > MAX_NODES {
+      return Err(Error::parse(ln, column, format!(
+        "the number of nodes {} exceeds the limit of {}", nlen, MAX_NODES)))
+    }
+    Ok(())
+}
+
+/// Returns the position, starting from one, of each node in a list of nodes.
+pub(crate) fn index(nodes: &[usize]) -> HashMap<usize, usize> {
+  nodes.iter().enumerate().map(|(i, &n)| (n, i + 1)).collect()
+}
+
+/// Set of the edges read from a graph file with the directions in which they
+/// were listed, which detects the repeated edges without scanning the
+/// adjacency lists.
+#[derive(Default)]
+pub(crate) struct EdgeSet(HashMap<(usize, usize), u8>);
+
+impl EdgeSet {
+  /// Inserts an edge and returns whether it was not read before.
+  pub(crate) fn insert(&mut self, e: (usize, usize)) -> bool {
+    self.insert_arc(e).0
+  }
+
+  /// Inserts an edge listed from `a` to `b` and returns whether the edge was
+  /// not read before and whether it was not read before in that direction.
+  pub(crate) fn insert_arc(&mut self, (a, b): (usize, usize))
+    -> (bool, bool) {
+      let direction = if a < b { 1 } else { 2 };
+      let listed = self.0.entry((cmp::min(a, b), cmp::max(a, b)))
+        .or_insert(0);
+      let new = (*listed == 0, *listed & direction == 0);
+      *listed |= direction;
+      new
+  }
+}
+
+/// Maps original node ids to nodes numbered from one in order of insertion.
+#[derive(Default)]
+pub(crate) struct IdMap {
+  nodes: HashMap<String, usize>,
+  ids: Vec<String>,
+}
+
+impl IdMap {
+  /// Returns the node of an id, inserting it in the graph if it is new.
+  pub(crate) fn insert(&mut self, id: &str, graph: &mut Graph) -> usize {
+    if let Some(&n) = self.nodes.get(id) { return n }
+    self.ids.push(id.to_string());
+    let n = self.ids.len();
+    self.nodes.insert(id.to_string(), n);
+    graph.insert_node(n);
+    n
+  }
+
+  /// Returns the id of a node.
+  pub(crate) fn id(&self, n: usize) -> &str {
+    &self.ids[n - 1]
+  }
+
+  /// Returns the ids ordered by node.
+  pub(crate) fn into_ids(self) -> Vec<String> {
+    self.ids
+  }
+}
+
+/// Compression formats of graph files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+  None,
+  Gzip,
+  Bzip2,
+  Xz,
+}
+
+impl Compression {
+  /// Returns the compression format identified by the first bytes of a file.
+  pub fn from_magic(bytes: &[u8]) -> Compression {
+    if bytes.starts_with(&[0x1f, 0x8b]) { Compression::Gzip }
+    else if bytes.starts_with(b"BZh") { Compression::Bzip2 }
+    else if bytes.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+      Compression::Xz
+    }
+    else { Compression::None }
+  }
+
+  /// Returns the compression format identified by the extension of a file.
+  pub fn from_path<P: AsRef<Path>>(path: P) -> Compression {
+    match path.as_ref().extension().and_then(|e| e.to_str()) {
+      Some("gz") => Compression::Gzip,
+      Some("bz2") => Compression::Bzip2,
+      Some("xz") => Compression::Xz,
+      _ => Compression::None,
+    }
+  }
+}
+
+/// Graph file formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+  /// DIMACS text format (`.clq`).
+  Dimacs,
+  /// DIMACS binary format (`.clq.b`).
+  DimacsBinary,
+  /// Whitespace separated edge list, such as the SNAP datasets.
+  EdgeList,
+  /// Matrix Market coordinate format (`.mtx`).
+  MatrixMarket,
+  /// METIS format (`.graph`).
+  Metis,
+  /// One adjacency list per line (`.adj`).
+  AdjList,
+  /// GraphML format (`.graphml`).
+  GraphMl,
+  /// GEXF format (`.gexf`).
+  Gexf,
+}
+
+impl Format {
+  /// Returns the format identified by the extension of a file, ignoring the
+  /// compression extension, if the extension is known.
+  pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Format> {
+    let mut path = path.as_ref();
+    if Compression::from_path(path) != Compression::None {
+      path = path.file_stem().map_or(path, Path::new);
+    }
+    match path.extension().and_then(|e| e.to_str()) {
+      Some("clq") | Some("col") | Some("dimacs") => Some(Format::Dimacs),
+      Some("b") => Some(Format::DimacsBinary),
+      Some("txt") | Some("edges") | Some("el") | Some("tsv") =>
+        Some(Format::EdgeList),
+      Some("mtx") => Some(Format::MatrixMarket),
+      Some("graph") | Some("metis") => Some(Format::Metis),
+      Some("adj") | Some("adjlist") => Some(Format::AdjList),
+      Some("graphml") => Some(Format::GraphMl),
+      Some("gexf") => Some(Format::Gexf),
+      _ => None,
+    }
+  }
+
+  /// Returns the format identified by the first bytes of a file content. The
+  /// METIS and adjacency list formats are not detected.
+  pub fn detect(bytes: &[u8]) -> Format {
+    if bytes.starts_with(b"%%MatrixMarket") { return Format::MatrixMarket }
+    // The XML formats are identified by their root element
+    let head = &bytes[..cmp::min(bytes.len(), 1024)];
+    if head.windows(8).any(|w| w == b"<graphml") { return Format::GraphMl }
+    if head.windows(5).any(|w| w == b"<gexf") { return Format::Gexf }
+    let mut lines = bytes.split(|&b| b == b'\n')
+      .map(|l| l.strip_suffix(b"\r").unwrap_or(l));
+    // The binary format starts with the length of its preamble
+    let first = lines.next().unwrap_or_default();
+    if !first.is_empty() && first.iter().all(u8::is_ascii_digit) {
+      if let Some(b'c') | Some(b'p') = lines.next().and_then(|l| l.first()) {
+        return Format::DimacsBinary
+      }
+    }
+    // The text format has its lines prefixed by their type
+    let first = bytes.split(|&b| b == b'\n')
+      .find(|l| !l.iter().all(u8::is_ascii_whitespace));
+    match first {
+      Some([b'c', s, ..]) | Some([b'p', s, ..]) if s.is_ascii_whitespace() =>
+        Format::Dimacs,
+      _ => Format::EdgeList,
+    }
+  }
+}
+
+impl FromStr for Format {
+  type Err = &'static str;
+
+  fn from_str(s: &str) -> Result<Format, Self::Err> {
+    match s {
+      "dimacs" => Ok(Format::Dimacs),
+      "binary" => Ok(Format::DimacsBinary),
+      "edgelist" => Ok(Format::EdgeList),
+      "mtx" => Ok(Format::MatrixMarket),
+      "metis" => Ok(Format::Metis),
+      "adjlist" => Ok(Format::AdjList),
+      "graphml" => Ok(Format::GraphMl),
+      "gexf" => Ok(Format::Gexf),
+      _ => Err("unknown graph file format"),
+    }
+  }
+}
+
+/// Reader that reports to the standard error how much of its source was
+/// consumed.
+pub struct Progress<R> {
+  inner: R,
+  read: u64,
+  total: Option<u64>,
+  reported: u64,
+  done: bool,
+}
+
+impl<R: Read> Progress<R> {
+  /// Number of bytes between two reports when the total size is unknown.
+  const STEP: u64 = 16 << 20;
+
+  /// Wraps a reader of a source with the given size in bytes, if known.
+  pub fn new(inner: R, total: Option<u64>) -> Progress<R> {
+    Progress { inner, read: 0, total, reported: 0, done: false }
+  }
+
+  fn report(&mut self, done: bool) {
+    let mib = self.read >> 20;
+    match self.total {
+      Some(total) if total > 0 => {
+        let percent = cmp::min(100, self.read * 100 / total);
+        if percent != self.reported {
+          self.reported = percent;
+          eprint!("\rReading: {:>3}% ({} MiB)", percent, mib);
+        }
+      },
+      _ => {
+        if self.read >= self.reported + Self::STEP || done {
+          self.reported = self.read;
+          eprint!("\rReading: {} MiB", mib);
+        }
+      },
+    }
+    if done { eprintln!(); }
+  }
+}
+
+impl<R: Read> Read for Progress<R> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let n = self.inner.read(buf)?;
+    self.read += n as u64;
+    // Report the end of the source only once
+    let done = n == 0 && !buf.is_empty() && !self.done;
+    self.done |= done;
+    if !self.done || done { self.report(done); }
+    Ok(n)
+  }
+}
+
+/// Opens the raw source of a graph file, or the standard input if the
+/// filename is `-`, and returns it with its size in bytes, if known.
+fn source(filename: &str) -> Result<(Box<dyn Read>, Option<u64>), Error> {
+  if filename == "-" { return Ok((Box::new(io::stdin()), None)) }
+  let file = File::open(filename)?;
+  let metadata = file.metadata()?;
+  #[cfg(feature = "mmap")]
+  {
+    if metadata.is_file() && metadata.len() > 0 {
+      // SAFETY: the graph file is only read and it is assumed that it is not
+      // modified by another process while it is mapped.
+      let map = unsafe { memmap2::Mmap::map(&file)? };
+      return Ok((Box::new(Cursor::new(map)), Some(metadata.len())))
+    }
+  }
+  let size = if metadata.is_file() { Some(metadata.len()) } else { None };
+  Ok((Box::new(file), size))
+}
+
+/// Opens a graph file, or the standard input if the filename is `-`, and
+/// transparently decompresses it.
+pub fn open(filename: &str) -> Result<Box<dyn Read>, Error> {
+  decompress(source(filename)?.0, Compression::from_path(filename))
+}
+
+/// Wraps a reader with the decoder of its compression format. The format is
+/// identified by the magic bytes of the content and, if they are not
+/// recognized, by the given fallback.
+pub fn decompress<R: Read + 'static>(reader: R, fallback: Compression)
+  -> Result<Box<dyn Read>, Error> {
+    let mut reader = BufReader::new(reader);
+    let compression = match Compression::from_magic(reader.fill_buf()?) {
+      Compression::None => fallback,
+      compression => compression,
+    };
+    Ok(match compression {
+      Compression::None => Box::new(reader),
+      Compression::Gzip => Box::new(MultiGzDecoder::new(reader)),
+      Compression::Bzip2 => Box::new(MultiBzDecoder::new(reader)),
+      Compression::Xz => Box::new(XzDecoder::new_multi_decoder(reader)),
+    })
+}
+
+/// Reads a graph from a source, building it while the source is consumed,
+/// and returns the respective instance. The format is detected from the
+/// content if it is not given. The weight attribute names the node attribute
+/// read as weight from the formats that have attributes.
+pub fn read_from<R: Read>(reader: R, format: Option<Format>, policy: Policy,
+  weight: Option<&str>) -> Result<Instance, Error> {
+    let mut reader = BufReader::with_capacity(1 << 16, reader);
+    let format = match format {
+      Some(format) => format,
+      None => Format::detect(reader.fill_buf()?),
+    };
+    match format {
+      Format::Dimacs => dimacs::read(reader, policy),
+      Format::DimacsBinary => binary::read(reader, policy),
+      Format::EdgeList => edgelist::read(reader, policy),
+      Format::MatrixMarket => mtx::read(reader, policy),
+      Format::Metis => metis::read(reader, policy),
+      Format::AdjList => adjlist::read(reader, policy),
+      Format::GraphMl => graphml::read(reader, policy, weight),
+      Format::Gexf => gexf::read(reader, policy, weight),
+    }
+}
+
+/// Reads a graph file, or the standard input if the filename is `-`, and
+/// returns the respective instance. The format is the given one or,
+/// otherwise, the one identified by the file extension or detected from the
+/// content.
+pub fn read_file(filename: &str, format: Option<Format>, policy: Policy,
+  weight: Option<&str>, progress: bool) -> Result<Instance, Error> {
+    let (mut source, size) = source(filename)?;
+    // Report the reading progress of the raw source
+    if progress { source = Box::new(Progress::new(source, size)); }
+    let reader = decompress(source, Compression::from_path(filename))?;
+    let format = format.or_else(|| Format::from_path(filename));
+    read_from(reader, format, policy, weight)
+}
+
+/// Reads a graph file and returns the respective instance. The format is
+/// the one given in the configuration or, otherwise, the one identified by
+/// the file extension or detected from the content.
+pub fn read(config: &Config) -> Result<Instance, Error> {
+  read_file(config.filename(), config.format(), config.policy(),
+    config.weight_attr(), config.is_progress())
+}
+
+/// Returns the edges of a graph with the nodes renumbered from one in
+/// ascending order, where each edge is listed once with its smallest node
+/// first and the edges are sorted.
+pub(crate) fn normalized_edges(graph: &Graph) -> Vec<(usize, usize)> {
+  let mut nodes = graph.nodes(); nodes.sort();
+  let index = index(&nodes);
+  let mut edges = vec![];
+  for n in nodes {
+    let a = index[&n];
+    let mut adjlst: Vec<usize> = graph.adjlst_of(n).iter()
+      .map(|an| index[an]).filter(|&b| b > a).collect();
+    adjlst.sort();
+    edges.extend(adjlst.into_iter().map(|b| (a, b)));
+  }
+  edges
+}
+
+/// Writes a graph in the given format with the given node weights, if the
+/// format has them. The nodes are renumbered from one in ascending order.
+pub fn write_to<W: Write>(writer: W, format: Format, graph: &Graph,
+  weights: Option<&[f64]>) -> Result<(), Error> {
+    match format {
+      Format::Dimacs => dimacs::write(writer, graph),
+      Format::DimacsBinary => binary::write(writer, graph),
+      Format::EdgeList => edgelist::write(writer, graph),
+      Format::MatrixMarket => mtx::write(writer, graph),
+      Format::Metis => metis::write(writer, graph, weights),
+      Format::AdjList => adjlist::write(writer, graph),
+      Format::GraphMl => graphml::write(writer, graph, weights),
+      Format::Gexf => gexf::write(writer, graph, weights),
+    }
+}
+
+/// Output file, possibly compressed, or the standard output.
+pub enum Output {
+  /// Standard output.
+  Stdout(io::Stdout),
+  /// Uncompressed file.
+  Plain(BufWriter<File>),
+  /// File compressed with gzip.
+  Gzip(GzEncoder<BufWriter<File>>),
+  /// File compressed with bzip2.
+  Bzip2(BzEncoder<BufWriter<File>>),
+  /// File compressed with xz.
+  Xz(XzEncoder<BufWriter<File>>),
+}
+
+impl Output {
+  /// Writes the trailer of the compressed formats and flushes the output,
+  /// which must be called to detect the errors of the last writes.
+  pub fn finish(self) -> Result<(), Error> {
+    let mut file = match self {
+      Output::Stdout(mut stdout) => return Ok(stdout.flush()?),
+      Output::Plain(file) => file,
+      Output::Gzip(encoder) => encoder.finish()?,
+      Output::Bzip2(encoder) => encoder.finish()?,
+      Output::Xz(encoder) => encoder.finish()?,
+    };
+    file.flush()?;
+    Ok(())
+  }
+
+  fn inner(&mut self) -> &mut dyn Write {
+    match self {
+      Output::Stdout(stdout) => stdout,
+      Output::Plain(file) => file,
+      Output::Gzip(encoder) => encoder,
+      Output::Bzip2(encoder) => encoder,
+      Output::Xz(encoder) => encoder,
+    }
+  }
+}
+
+impl Write for Output {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.inner().write(buf)
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    self.inner().flush()
+  }
+}
+
+/// Creates a file, or returns the standard output if the filename is `-`,
+/// and transparently compresses it according to its extension.
+pub fn create(filename: &str) -> Result<Output, Error> {
+  if filename == "-" { return Ok(Output::Stdout(io::stdout())) }
+  let file = BufWriter::new(File::create(filename)?);
+  Ok(match Compression::from_path(filename) {
+    Compression::None => Output::Plain(file),
+    Compression::Gzip =>
+      Output::Gzip(GzEncoder::new(file, flate2::Compression::default())),
+    Compression::Bzip2 =>
+      Output::Bzip2(BzEncoder::new(file, bzip2::Compression::default())),
+    Compression::Xz => Output::Xz(XzEncoder::new(file, 6)),
+  })
+}
+
+/// Writes a graph file, or to the standard output if the filename is `-`, in
+/// the given format or, otherwise, in the one identified by the file
+/// extension, which defaults to DIMACS.
+pub fn write_file(filename: &str, format: Option<Format>, graph: &Graph,
+  weights: Option<&[f64]>) -> Result<(), Error> {
+    let format = format.or_else(|| Format::from_path(filename))
+      .unwrap_or(Format::Dimacs);
+    let mut writer = create(filename)?;
+    write_to(&mut writer, format, graph, weights)?;
+    writer.finish()
+}
+
+/// Writes a graph in the DOT format to a file, or to the standard output if
+/// the filename is `-`, with a clique highlighted or, if requested, only the
+/// clique subgraph.
+pub fn write_dot(filename: &str, graph: &Graph, clique: &Graph,
+  ids: Option<&[String]>, clique_only: bool) -> Result<(), Error> {
+    let mut writer = create(filename)?;
+    if clique_only { dot::write_clique(&mut writer, clique, ids)?; }
+    else { dot::write(&mut writer, graph, clique, ids)?; }
+    writer.finish()
+}
+
+/// Reads a solution file and returns its cliques as lists of nodes of the
+/// instance.
+pub fn read_solution(filename: &str, instance: &Instance)
+  -> Result<SolutionFile, Error> {
+    solution::read(BufReader::new(open(filename)?), instance)
+}
+
+/// Returns the path of the expected results of an instance file, which is
+/// `expected/R_<stem>.txt` next to it as in `db/`.
+pub fn expected_path<P: AsRef<Path>>(filename: P) -> PathBuf {
+  let filename = filename.as_ref();
+  let stem = filename.file_stem().unwrap_or_default().to_string_lossy();
+  filename.with_file_name("expected").join(format!("R_{}.txt", stem))
+}
+
+/// Returns the name of an instance file without its directories, or `stdin`
+/// if the filename is `-`.
+pub fn instance_name(filename: &str) -> String {
+  if filename == "-" { return "stdin".to_string() }
+  Path::new(filename).file_name()
+    .map_or(filename.to_string(), |n| n.to_string_lossy().into_owned())
+}
+
+/// Writes result reports to a file, or to the standard output if the filename
+/// is `-`, in the given format or, otherwise, in the one identified by the
+/// file extension.
+pub fn write_report(filename: &str, format: Option<ReportFormat>,
+  reports: &[Report]) -> Result<(), Error> {
+    let format = format.unwrap_or_else(|| ReportFormat::from_path(filename));
+    let mut writer = create(filename)?;
+    report::write(&mut writer, format, reports)?;
+    writer.finish()
+}
+
+/// Policies for writing a result file that already exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overwrite {
+  /// Replaces the existing file.
+  Replace,
+  /// Fails without touching the existing file.
+  Fail,
+  /// Writes to the first free path with a numeric suffix appended.
+  Rename,
+}
+
+impl FromStr for Overwrite {
+  type Err = &'static str;
+
+  fn from_str(s: &str) -> Result<Overwrite, Self::Err> {
+    match s {
+      "replace" => Ok(Overwrite::Replace),
+      "fail" => Ok(Overwrite::Fail),
+      "rename" => Ok(Overwrite::Rename),
+      _ => Err("unknown overwrite policy"),
+    }
+  }
+}
+
+/// Returns the default directory of the result files, which is
+/// `max-clique-solutions` in the home directory or, if there is none, in the
+/// current directory.
+pub fn solutions_dir() -> PathBuf {
+  dirs::home_dir().unwrap_or_default().join("max-clique-solutions")
+}
+
+/// Returns the path of the result file of an instance file: the output path
+/// if it was given or, otherwise, `result_<name>` in the output directory,
+/// which defaults to `solutions_dir()`.
+pub fn result_path(filename: &str, output: Option<&Path>,
+  output_dir: Option<&Path>) -> PathBuf {
+    if let Some(output) = output { return output.to_path_buf() }
+    let dir = output_dir.map_or_else(solutions_dir, Path::to_path_buf);
+    dir.join(format!("result_{}", instance_name(filename)))
+}
+
+/// Writes a file of a graph with the original ids of its nodes, if the graph
+/// file has them, creating the missing parent directories. Returns the path
+/// actually written, which differs from the given one if the file exists and
+/// the overwrite policy renames it.
+pub fn write(path: &Path, result: &Graph, ids: Option<&[String]>,
+  overwrite: Overwrite) -> Result<PathBuf, Error> {
+    // Create the directories if they do not exist
+    if let Some(parent) = path.parent() { fs::create_dir_all(parent)?; }
+    // Get resulting graph nodes
+    let mut nodes = result.nodes(); nodes.sort();
+    let nodes: Vec<String> = nodes.iter().map(|&n| label(ids, n)).collect();
+    let content = format!("{}\n", nodes.join(" "));
+    // Create the file according to the overwrite policy
+    let mut options = OpenOptions::new();
+    options.write(true);
+    let (path, mut file) = match overwrite {
+      Overwrite::Replace =>
+        (path.to_path_buf(), options.create(true).truncate(true).open(path)?),
+      Overwrite::Fail =>
+        (path.to_path_buf(), options.create_new(true).open(path)?),
+      Overwrite::Rename => {
+        options.create_new(true);
+        let mut candidate = path.to_path_buf();
+        let mut i = 0;
+        loop {
+          match options.open(&candidate) {
+            Ok(file) => break (candidate, file),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+              i += 1;
+              let mut name = path.as_os_str().to_owned();
+              name.push(format!(".{}", i));
+              candidate = PathBuf::from(name);
+            },
+            Err(e) => return Err(e.into()),
+          }
+        }
+      },
+    };
+    // Write the file
+    file.write_all(content.as_bytes())?;
+    Ok(path)
+}
@@ -0,0 +1,115 @@
+use std::cmp;
+use std::io::{BufRead, Write};
+use crate::error::Error;
+use crate::graph::Graph;
+use super::dimacs::{self, number, MAX_TOKENS};
+use super::{EdgeSet, Instance, Policy};
+
+/// Reads a Matrix Market `.mtx` file holding the adjacency matrix of a graph
+/// and returns the respective graph.
+///
+/// Only square matrices in the coordinate format are supported and the
+/// values of the entries are ignored. Entries on the diagonal are self-loops.
+/// General matrices store both triangles, so their repeated edges are merged
+/// silently, while repeated edges of symmetric matrices are duplicates. A
+/// wrong number of entries, self-loops and duplicate edges are handled
+/// according to the policy.
+pub fn read<R: BufRead>(mut reader: R, policy: Policy)
+  -> Result<Instance, Error> {
+    let mut graph: Option<Graph> = None;
+    let mut diagnostics = vec![];
+    let mut edges = EdgeSet::default();
+    // Read the header
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let header: Vec<String> = line.split_whitespace()
+      .map(|t| t.to_lowercase()).collect();
+    if header.len() != 5 || header[0] != "%%matrixmarket"
+      || header[1] != "matrix" {
+        return Err(Error::parse(1, 1,
+          "expected `%%MatrixMarket matrix <format> <field> <symmetry>`"))
+    }
+    if header[2] != "coordinate" {
+      return Err(Error::parse(1, 1,
+        format!("unsupported format `{}`", header[2])))
+    }
+    let general = header[4] == "general";
+    // Expected and read number of entries and the size line number
+    let (mut nnz, mut count, mut sln) = (0, 0, 0);
+    let mut ln = 1;
+    loop {
+      // Read the next line reusing the line buffer
+      line.clear();
+      if reader.read_line(&mut line)? == 0 { break }
+      ln += 1;
+      let mut tks = [(0, ""); MAX_TOKENS];
+      let len = dimacs::tokens(&line, &mut tks);
+      // Ignore empty lines and comments
+      if len == 0 || tks[0].1.starts_with('%') { continue; }
+      let tks = &tks[..cmp::min(len, MAX_TOKENS)];
+      let graph = match graph.as_mut() {
+        Some(graph) => graph,
+        None => {
+          // The first line after the comments holds the matrix size
+          if len != 3 {
+            return Err(Error::parse(ln, tks[0].0,
+              "expected `<rows> <columns> <entries>`"))
+          }
+          let (rows, columns) = (number(ln, tks[0])?, number(ln, tks[1])?);
+          if rows != columns {
+            return Err(Error::parse(ln, tks[0].0, "the matrix is not square"))
+          }
+          super::check_nodes(ln, tks[0].0, rows)?;
+          nnz = number(ln, tks[2])?;
+          sln = ln;
+          graph = Some(Graph::new(rows));
+          continue;
+        },
+      };
+      if len < 2 {
+        return Err(Error::parse(ln, tks[0].0, "expected `<row> <column>`"))
+      }
+      let (a, b) = (number(ln, tks[0])?, number(ln, tks[1])?);
+      count += 1;
+      // Check if the nodes belong to the graph
+      for &(n, (column, _)) in &[(a, tks[0]), (b, tks[1])] {
+        if !graph.contains_node(n) {
+          return Err(Error::parse(ln, column,
+            format!("the node {} is out of range 1..={}", n, graph.nlen())))
+        }
+      }
+      // Check for self-loops and duplicate edges
+      if a == b {
+        policy.report(&mut diagnostics, ln, tks[0].0,
+          format!("self-loop on node {}", a))?;
+      } else if !edges.insert((a, b)) {
+        if !general {
+          policy.report(&mut diagnostics, ln, tks[0].0,
+            format!("duplicate edge ({}, {})", a, b))?;
+        }
+      } else {
+        graph.try_insert_edge((a, b))?;
+      }
+    }
+    let graph = match graph {
+      Some(graph) => graph,
+      None => return Err(Error::parse(ln, 1, "missing matrix size")),
+    };
+    // Check if the matrix has the right number of entries
+    if count != nnz {
+      policy.report(&mut diagnostics, sln, 1,
+        format!("expected {} entries but found {}", nnz, count))?;
+    }
+    Ok(Instance { graph, ids: None, weights: None, diagnostics })
+}
+
+/// Writes the adjacency matrix of a graph in the Matrix Market symmetric
+/// pattern format, listing only its lower triangle. The nodes are renumbered
+/// from one in ascending order.
+pub fn write<W: Write>(mut writer: W, graph: &Graph) -> Result<(), Error> {
+  let edges = super::normalized_edges(graph);
+  writeln!(writer, "%%MatrixMarket matrix coordinate pattern symmetric")?;
+  writeln!(writer, "{} {} {}", graph.nlen(), graph.nlen(), edges.len())?;
+  for (a, b) in edges { writeln!(writer, "{} {}", b, a)?; }
+  Ok(())
+}
@@ -0,0 +1,130 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use serde::Serialize;
+use crate::error::Error;
+use crate::graph::Graph;
+use crate::solver::Solution;
+use super::label;
+
+/// Formats of the result reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+  Json,
+  Csv,
+}
+
+impl ReportFormat {
+  /// Identifies the report format by the file extension, which defaults to
+  /// JSON.
+  pub fn from_path<P: AsRef<Path>>(path: P) -> ReportFormat {
+    match path.as_ref().extension().and_then(|e| e.to_str()) {
+      Some("csv") => ReportFormat::Csv,
+      _ => ReportFormat::Json,
+    }
+  }
+}
+
+impl FromStr for ReportFormat {
+  type Err = &'static str;
+
+  fn from_str(s: &str) -> Result<ReportFormat, Self::Err> {
+    match s {
+      "json" => Ok(ReportFormat::Json),
+      "csv" => Ok(ReportFormat::Csv),
+      _ => Err("unknown report format"),
+    }
+  }
+}
+
+/// Machine-readable result of a solver run over an instance.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Report {
+  /// Name of the instance file.
+  pub instance: String,
+  /// Number of nodes of the graph.
+  pub nodes: usize,
+  /// Number of edges of the graph.
+  pub edges: usize,
+  /// Edge density of the graph.
+  pub density: f64,
+  /// Name of the solver.
+  pub solver: String,
+  /// Ids of the clique nodes in ascending order of the node numbers.
+  pub clique: Vec<String>,
+  /// Number of nodes of the clique.
+  pub size: usize,
+  /// Total weight of the clique nodes, if the graph is weighted.
+  pub weight: Option<f64>,
+  /// Wall time spent by the solver in seconds.
+  pub runtime: f64,
+  /// Whether the clique is known to be a maximum one.
+  pub optimal: bool,
+  /// Number of search nodes visited by the solver.
+  pub search_nodes: u64,
+}
+
+impl Report {
+  /// Returns the report of a solution found for a graph, where `ids` and
+  /// `weights` are the optional node ids and weights of the instance.
+  pub fn new(instance: &str, graph: &Graph, solver: &str,
+    solution: &Solution, ids: Option<&[String]>, weights: Option<&[f64]>)
+    -> Report {
+      let (n, m) = (graph.nlen(), graph.elen());
+      let density = if n < 2 { 0.0 }
+        else { 2.0 * m as f64 / (n as f64 * (n - 1) as f64) };
+      let mut nodes = solution.clique.nodes(); nodes.sort();
+      let weight = weights.map(|w| nodes.iter().map(|&n| w[n - 1]).sum());
+      Report { instance: instance.to_string(), nodes: n, edges: m, density,
+        solver: solver.to_string(),
+        clique: nodes.iter().map(|&n| label(ids, n)).collect(),
+        size: nodes.len(), weight,
+        runtime: solution.runtime.as_secs_f64(),
+        optimal: solution.optimal, search_nodes: solution.nodes }
+  }
+}
+
+/// Header of the CSV reports.
+const CSV_HEADER: &str =
+  "instance,nodes,edges,density,solver,clique,size,weight,runtime,optimal,\
+  search_nodes";
+
+/// Quotes a CSV field if it holds a separator, a quote or a line break.
+pub(crate) fn csv_field(field: &str) -> String {
+  if field.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    field.to_string()
+  }
+}
+
+impl fmt::Display for Report {
+  /// Formats the report as a CSV row, where the clique ids are separated by
+  /// spaces.
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{},{},{},{},{},{},{},{},{},{},{}", csv_field(&self.instance),
+      self.nodes, self.edges, self.density, csv_field(&self.solver),
+      csv_field(&self.clique.join(" ")), self.size,
+      self.weight.map_or(String::new(), |w| w.to_string()), self.runtime,
+      self.optimal, self.search_nodes)
+  }
+}
+
+/// Writes the reports in the given format, either as a JSON array or as CSV
+/// rows preceded by a header.
+pub fn write<W: Write>(mut writer: W, format: ReportFormat, reports: &[Report])
+  -> Result<(), Error> {
+    match format {
+      ReportFormat::Json => {
+        serde_json::to_writer_pretty(&mut writer, reports)
+          .map_err(io::Error::from)?;
+        writeln!(writer)?;
+      },
+      ReportFormat::Csv => {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for report in reports { writeln!(writer, "{}", report)?; }
+      },
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use crate::error::Error;
+use super::Instance;
+
+/// Cliques listed in a solution file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolutionFile {
+  /// Cliques as lists of nodes of the instance.
+  pub cliques: Vec<Vec<usize>>,
+  /// Size of the cliques given by a note, if there is one.
+  pub noted_size: Option<usize>,
+}
+
+impl SolutionFile {
+  /// Returns the size of the first clique or, if no clique is listed, the
+  /// one given by a note.
+  pub fn size(&self) -> Option<usize> {
+    self.cliques.first().map(Vec::len).or(self.noted_size)
+  }
+}
+
+/// Returns the size `k` of a `<k>-clique` word, ignoring the surrounding
+/// punctuation.
+fn noted_size(word: &str) -> Option<usize> {
+  word.trim_matches(['(', ')', ',', '.', ';']).strip_suffix("-clique")
+    .and_then(|k| k.parse().ok())
+}
+
+/// Reads a solution file and returns its cliques as lists of nodes of the
+/// instance.
+///
+/// Each line holds one clique as the whitespace separated ids of its nodes,
+/// which are the original ids of the instance if it has them or the node
+/// numbers otherwise. This covers the result files and the expected results
+/// of `db/expected`, which may list several maximum cliques. Empty lines and
+/// lines starting with `#` are ignored, and unknown or repeated ids are
+/// errors. Lines starting with `(` or holding a `<k>-clique` word are notes,
+/// such as `Any 2-clique`, and the latter give the size of the cliques.
+pub fn read<R: BufRead>(mut reader: R, instance: &Instance)
+  -> Result<SolutionFile, Error> {
+    let nodes: Option<HashMap<&str, usize>> = instance.ids.as_ref().map(|ids|
+      ids.iter().enumerate().map(|(i, id)| (id.as_str(), i + 1)).collect());
+    let mut cliques = vec![];
+    let mut size = None;
+    let mut line = String::new();
+    let mut ln = 0;
+    loop {
+      // Read the next line reusing the line buffer
+      line.clear();
+      if reader.read_line(&mut line)? == 0 { break }
+      ln += 1;
+      // Ignore empty lines and comments
+      let trimmed = line.trim();
+      if trimmed.is_empty() || trimmed.starts_with('#') { continue; }
+      // Keep the clique size given by notes
+      let note = line.split_whitespace().find_map(noted_size);
+      if note.is_some() || trimmed.starts_with('(') {
+        size = size.or(note);
+        continue;
+      }
+      let mut clique = vec![];
+      for token in line.split_whitespace() {
+        let column = token.as_ptr() as usize - line.as_ptr() as usize + 1;
+        let n = match &nodes {
+          Some(nodes) => nodes.get(token).copied(),
+          None => token.parse::<usize>().ok()
+            .filter(|&n| instance.graph.contains_node(n)),
+        };
+        let n = n.ok_or_else(|| Error::parse(ln, column,
+          format!("unknown node `{}`", token)))?;
+        if clique.contains(&n) {
+          return Err(Error::parse(ln, column,
+            format!("duplicate node `{}`", token)))
+        }
+        clique.push(n);
+      }
+      cliques.push(clique);
+    }
+    Ok(SolutionFile { cliques, noted_size: size })
+}
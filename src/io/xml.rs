@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use crate::error::Error;
+use crate::graph::Graph;
+use super::{Diagnostic, EdgeSet, IdMap, Instance, Policy};
+
+/// Returns the line and column of a byte offset of a content.
+pub(super) fn position(content: &str, offset: usize) -> (usize, usize) {
+  Cursor::default().position(content, offset)
+}
+
+/// Line and column of the last byte offset looked up in a content, from which
+/// the following offsets are reached without scanning the content again.
+#[derive(Default)]
+struct Cursor {
+  offset: usize,
+  line: usize,
+  start: usize,
+}
+
+impl Cursor {
+  /// Returns the line and column of a byte offset of a content.
+  fn position(&mut self, content: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(content.len());
+    if offset < self.offset || self.line == 0 {
+      *self = Cursor { offset: 0, line: 1, start: 0 };
+    }
+    let bytes = &content.as_bytes()[self.offset..offset];
+    for (i, _) in bytes.iter().enumerate().filter(|(_, &b)| b == b'\n') {
+      self.line += 1;
+      self.start = self.offset + i + 1;
+    }
+    self.offset = offset;
+    (self.line, offset - self.start + 1)
+  }
+}
+
+/// Creates a parse error at the current position of a reader.
+pub(super) fn error<S: Into<String>>(content: &str, reader: &Reader<&[u8]>,
+  message: S) -> Error {
+    let (line, column) = position(content, reader.buffer_position() as usize);
+    Error::parse(line, column, message)
+}
+
+/// Creates a parse error from an error of the XML reader.
+pub(super) fn xml_error(content: &str, reader: &Reader<&[u8]>,
+  e: quick_xml::Error) -> Error {
+    let (line, column) = position(content, reader.error_position() as usize);
+    Error::parse(line, column, format!("invalid XML: {}", e))
+}
+
+/// Returns the unescaped value of an attribute of an element.
+pub(super) fn attribute(e: &BytesStart, name: &str) -> Option<String> {
+  let attr = e.try_get_attribute(name).ok()??;
+  attr.unescape_value().ok().map(|v| v.into_owned())
+}
+
+/// Returns the value of an attribute of an element or an error if it is
+/// missing.
+pub(super) fn required(content: &str, reader: &Reader<&[u8]>, e: &BytesStart,
+  name: &str) -> Result<String, Error> {
+    attribute(e, name).ok_or_else(|| error(content, reader,
+      format!("missing attribute `{}` of element `{}`", name,
+        String::from_utf8_lossy(e.local_name().as_ref()))))
+}
+
+/// Parses a weight at the current position of a reader.
+pub(super) fn weight(content: &str, reader: &Reader<&[u8]>, value: &str)
+  -> Result<f64, Error> {
+    match value.trim().parse::<f64>() {
+      Ok(w) if w.is_finite() => Ok(w),
+      _ => Err(error(content, reader, format!("invalid weight `{}`", value))),
+    }
+}
+
+/// Builds the instance of a graph file with string node ids.
+#[derive(Default)]
+pub(super) struct Builder {
+  graph: Graph,
+  edges: EdgeSet,
+  ids: IdMap,
+  weights: HashMap<usize, f64>,
+  diagnostics: Vec<Diagnostic>,
+  cursor: Cursor,
+}
+
+impl Builder {
+  /// Returns the node of an id, inserting it if it is new.
+  pub(super) fn node(&mut self, id: &str) -> usize {
+    self.ids.insert(id, &mut self.graph)
+  }
+
+  /// Sets the weight of a node.
+  pub(super) fn weight(&mut self, n: usize, weight: f64) {
+    self.weights.insert(n, weight);
+  }
+
+  /// Inserts an edge found at the given byte offset of the content, whose
+  /// line and column are computed only to report a problem. Repeated edges
+  /// are merged silently if the graph is directed and handled according to
+  /// the policy otherwise, as well as self-loops.
+  pub(super) fn edge(&mut self, (a, b): (&str, &str), directed: bool,
+    policy: Policy, (content, offset): (&str, usize)) -> Result<(), Error> {
+      let (na, nb) = (self.node(a), self.node(b));
+      let message = if na == nb {
+        format!("self-loop on node {}", a)
+      } else if self.edges.insert((na, nb)) {
+        return self.graph.try_insert_edge((na, nb))
+      } else if !directed {
+        format!("duplicate edge ({}, {})", a, b)
+      } else {
+        return Ok(())
+      };
+      let (line, column) = self.cursor.position(content, offset);
+      policy.report(&mut self.diagnostics, line, column, message)
+  }
+
+  /// Returns the instance, where the nodes without weight get the default
+  /// one if the weights were requested.
+  pub(super) fn finish(mut self, weighted: bool, default: Option<f64>,
+    policy: Policy) -> Result<Instance, Error> {
+      if self.graph.is_empty() { return Err(Error::EmptyGraph) }
+      let weights = if weighted {
+        let mut weights = vec![];
+        for n in 1..=self.graph.nlen() {
+          let w = match (self.weights.get(&n), default) {
+            (Some(&w), _) | (None, Some(w)) => w,
+            (None, None) => {
+              policy.report(&mut self.diagnostics, 1, 1,
+                format!("the node {} has no weight", self.ids.id(n)))?;
+              1.0
+            },
+          };
+          weights.push(w);
+        }
+        Some(weights)
+      } else { None };
+      Ok(Instance { graph: self.graph, ids: Some(self.ids.into_ids()),
+        weights, diagnostics: self.diagnostics })
+  }
+}
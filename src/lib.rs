@@ -8,33 +8,317 @@
 #[cfg(test)]
 mod tests;
 
+pub mod bench;
+pub mod bounds;
+pub mod color;
+mod error;
+pub mod generate;
 pub mod graph;
 pub mod io;
 pub mod solver;
+pub mod verify;
 
-use std::error::Error;
+use std::io::Write;
+use std::path::PathBuf;
+
+pub use error::Error;
 
 /// Perform file reading and applies the query.
-pub fn run(config: io::Config) -> Result<(), Box<dyn Error>> {
+pub fn run(config: io::Config) -> Result<(), Box<dyn std::error::Error>> {
+  // Keep the standard output for the report if it is written there
+  let verbose = config.report() != Some("-");
   // Read the graph from file
-  let graph = io::read(&config)?;
-  if graph.nlen() <= 10 || graph.elen() <= 10 {
-    println!("Read graph:");
-    println!("  {:?}", graph);
-  } else {
-    println!("WARNING: the given graph is too big and cannot be printed.");
+  let instance = io::read(&config)?;
+  for d in &instance.diagnostics { eprintln!("WARNING: {}.", d); }
+  let graph = &instance.graph;
+  if verbose {
+    if graph.nlen() <= 10 || graph.elen() <= 10 {
+      println!("Read graph:");
+      println!("  {:?}", graph);
+    } else {
+      println!("WARNING: the given graph is too big and cannot be printed.");
+    }
   }
   // Run the max clique solver
-  let result = solver::solve(&graph, &config.solver())?;
-  // Check result size
-  if result.nlen() <= 10 || result.elen() <= 10 {
-    println!("Maximum clique subgraph:");
-    println!("  {:?}", result);
+  let (solution, solver, weights) = if config.is_weighted() {
+    let weights = instance.weights.as_deref()
+      .ok_or("the graph file has no node weights")?;
+    let solution = solver::solve_weighted_with(graph, weights,
+      config.time_limit())?;
+    if verbose {
+      let weight: f64 = solution.clique.nodes().iter()
+        .map(|n| weights[n - 1]).sum();
+      println!("Maximum clique weight: {}", weight);
+    }
+    (solution, "Weighted".to_string(), Some(weights))
+  } else if config.is_preprocess() {
+    let reduction = solver::preprocess(graph)?;
+    if verbose {
+      println!("Preprocessing removed {} nodes and {} edges (heuristic clique \
+        of {} nodes)", reduction.removed_nodes, reduction.removed_edges,
+        reduction.incumbent.len());
+    }
+    let solution = solver::solve_reduced(graph, &reduction, config.solver(),
+      config.time_limit())?;
+    (solution, config.solver().to_string(), None)
   } else {
-    println!("WARNING: the resulting graph is too big and cannot be printed.");
+    let solution = solver::solve_with(graph, config.solver(),
+      config.time_limit())?;
+    (solution, config.solver().to_string(), None)
+  };
+  let result = &solution.clique;
+  if !solution.optimal {
+    eprintln!("WARNING: the time limit was reached and the clique may not be \
+      maximum.");
+  }
+  // Check result size
+  if verbose {
+    if result.nlen() <= 10 || result.elen() <= 10 {
+      println!("Maximum clique subgraph:");
+      println!("  {:?}", result);
+    } else {
+      println!("WARNING: the resulting graph is too big and cannot be \
+        printed.");
+    }
+  }
+  // Report the original ids of the clique nodes
+  if verbose && instance.ids.is_some() {
+    let mut nodes = result.nodes(); nodes.sort();
+    let ids: Vec<String> = nodes.iter().map(|&n| instance.id(n)).collect();
+    println!("Maximum clique ids: {}", ids.join(" "));
+  }
+  // Check if the result must be drawn
+  if let Some(filename) = config.dot() {
+    io::write_dot(filename, graph, result, instance.ids.as_deref(),
+      config.is_dot_clique())?;
   }
   // Check if the result must be saved
-  if config.is_save() { io::write(&config.filename(), &result)?; }
+  if config.is_save() {
+    let path = io::result_path(config.filename(), config.output(),
+      config.output_dir());
+    let path = io::write(&path, result, instance.ids.as_deref(),
+      config.overwrite())?;
+    if verbose { println!("Result saved to {}", path.display()); }
+  }
+  // Check if the result must be reported
+  if let Some(filename) = config.report() {
+    let report = io::Report::new(&io::instance_name(config.filename()),
+      graph, &solver, &solution, instance.ids.as_deref(), weights);
+    io::write_report(filename, config.report_format(), &[report])?;
+  }
   // Return Ok
   Ok(())
 }
+
+/// Converts a graph file to another format. Self-loops and duplicate edges
+/// are removed with a warning and the nodes are renumbered from one.
+pub fn convert(config: io::ConvertConfig)
+  -> Result<(), Box<dyn std::error::Error>> {
+    // Read the graph skipping the malformed edges
+    let instance = io::read_file(config.input(), config.from(),
+      io::Policy::Lenient, config.weight_attr(), config.is_progress())?;
+    for d in &instance.diagnostics { eprintln!("WARNING: {}.", d); }
+    // Write the normalised graph
+    io::write_file(config.output(), config.to(), &instance.graph,
+      instance.weights.as_deref())?;
+    Ok(())
+}
+
+/// Checks the cliques of a solution file against a graph and reports whether
+/// each one is a maximal clique and how its size compares to the optimum.
+pub fn verify(config: io::VerifyConfig)
+  -> Result<(), Box<dyn std::error::Error>> {
+    // Read the graph and the claimed cliques
+    let instance = io::read_file(config.filename(), config.format(),
+      config.policy(), None, false)?;
+    for d in &instance.diagnostics { eprintln!("WARNING: {}.", d); }
+    let cliques = io::read_solution(config.solution(), &instance)?.cliques;
+    if cliques.is_empty() {
+      return Err("the solution file has no clique".into())
+    }
+    // Get the known optimum from the expected results, if there are any
+    let optimum = match (config.optimum(), config.expected()) {
+      (Some(optimum), _) => Some(optimum),
+      (None, Some(expected)) => io::read_solution(expected, &instance)?
+        .size(),
+      (None, None) => {
+        let expected = io::expected_path(config.filename());
+        if expected.is_file() {
+          io::read_solution(&expected.to_string_lossy(), &instance)?.size()
+        } else {
+          None
+        }
+      },
+    };
+    // Verify each clique
+    let mut valid = true;
+    for (i, clique) in cliques.iter().enumerate() {
+      let result = verify::verify(&instance.graph, clique)?;
+      println!("Clique {}: {} nodes", i + 1, result.size);
+      match result.missing_edge {
+        Some((a, b)) => {
+          valid = false;
+          println!("  clique: no, {} and {} are not adjacent",
+            instance.id(a), instance.id(b));
+        },
+        None => println!("  clique: yes"),
+      }
+      if result.is_clique() {
+        match result.extension {
+          Some(n) => println!("  maximal: no, {} extends it", instance.id(n)),
+          None => println!("  maximal: yes"),
+        }
+      }
+      if let Some(optimum) = optimum {
+        let status = match result.size.cmp(&optimum) {
+          std::cmp::Ordering::Less => "below the optimum",
+          std::cmp::Ordering::Equal => "optimal",
+          std::cmp::Ordering::Greater => "above the known optimum",
+        };
+        println!("  optimum: {} ({})", optimum, status);
+      }
+    }
+    if !valid { return Err("the solution has an invalid clique".into()) }
+    Ok(())
+}
+
+/// Runs the solvers over the instances and prints a table of the
+/// measurements, which are also reported if requested.
+pub fn bench(config: io::BenchConfig)
+  -> Result<(), Box<dyn std::error::Error>> {
+    // Keep the standard output for the report if it is written there
+    let verbose = config.report() != Some("-");
+    // Get the instance files
+    let mut paths = vec![];
+    for pattern in config.instances() {
+      paths.extend(bench::instances(pattern)?);
+    }
+    if paths.is_empty() { return Err("no instance was found".into()) }
+    // Run each solver over each instance
+    let mut rows = vec![];
+    for path in &paths {
+      let filename = path.to_string_lossy();
+      let instance = io::read_file(&filename, None, io::Policy::Strict, None,
+        false)?;
+      let name = io::instance_name(&filename);
+      for &solver in config.solvers() {
+        if verbose { eprintln!("Running {} on {}", solver, name); }
+        rows.push(bench::run(&name, &instance, solver, config.time_limit(),
+          config.repetitions())?);
+      }
+    }
+    if verbose { bench::write_table(std::io::stdout(), &rows)?; }
+    // Check if the measurements must be reported
+    if let Some(filename) = config.report() {
+      let format = config.report_format()
+        .unwrap_or_else(|| io::ReportFormat::from_path(filename));
+      let mut writer = io::create(filename)?;
+      bench::write(&mut writer, format, &rows)?;
+      writer.finish()?;
+    }
+    Ok(())
+}
+
+/// Generates a graph and writes it in the DIMACS format together with its
+/// expected results, which hold the maximum clique of the model, if it is
+/// known with high probability, or the one found by the branch and bound
+/// within the time limit.
+pub fn generate(config: io::GenerateConfig)
+  -> Result<(), Box<dyn std::error::Error>> {
+    let generated = generate::generate(config.model(), config.seed())?;
+    let graph = &generated.graph;
+    // Write the graph
+    let mut writer = io::create(config.output())?;
+    writeln!(writer, "c Generated by max-clique: {} seed={}", config.model(),
+      config.seed())?;
+    io::dimacs::write(&mut writer, graph)?;
+    writer.finish()?;
+    // Check if the expected results must be written
+    let expected = match (config.expected(), config.output()) {
+      (Some(expected), _) => PathBuf::from(expected),
+      (None, "-") => return Ok(()),
+      (None, output) => io::expected_path(output),
+    };
+    // Get a maximum clique
+    let clique = match generated.maximum_clique() {
+      Some(clique) => clique.to_vec(),
+      None => {
+        let solution = solver::solve_with(graph, &io::Solver::BranchAndBound,
+          Some(config.time_limit()))?;
+        if !solution.optimal {
+          eprintln!("WARNING: the time limit was reached and the expected \
+            results were not written.");
+          return Ok(())
+        }
+        let mut clique = solution.clique.nodes(); clique.sort();
+        clique
+      },
+    };
+    if let Some(parent) = expected.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    let clique: Vec<String> = clique.iter().map(usize::to_string).collect();
+    std::fs::write(&expected, format!("{}\n", clique.join(" ")))?;
+    Ok(())
+}
+
+/// Prints the nodes of each color by their original ids.
+fn print_classes(instance: &io::Instance, coloring: &color::Coloring) {
+  for (c, class) in coloring.classes().iter().enumerate() {
+    let ids: Vec<String> = class.iter().map(|&n| instance.id(n)).collect();
+    println!("  color {}: {}", c + 1, ids.join(" "));
+  }
+}
+
+/// Colors the nodes of a graph with each algorithm and prints the number of
+/// colors used, which is an upper bound of the maximum clique size. The
+/// chromatic number is also found if requested, and it is certified by a
+/// clique of the same size if there is one.
+pub fn color(config: io::ColorConfig)
+  -> Result<(), Box<dyn std::error::Error>> {
+    let instance = io::read_file(config.filename(), config.format(),
+      config.policy(), None, false)?;
+    for d in &instance.diagnostics { eprintln!("WARNING: {}.", d); }
+    for &algorithm in config.algorithms() {
+      let coloring = color::color(&instance.graph, algorithm);
+      println!("{}: {} colors", algorithm, coloring.count());
+      if config.is_classes() { print_classes(&instance, &coloring); }
+    }
+    if config.is_exact() {
+      let result = color::chromatic_number(&instance.graph,
+        config.time_limit())?;
+      if !result.optimal {
+        eprintln!("WARNING: the time limit was reached and the coloring may \
+          not be minimum.");
+      }
+      println!("exact: {} colors", result.coloring.count());
+      if config.is_classes() { print_classes(&instance, &result.coloring); }
+      let ids: Vec<String> = result.clique.iter().map(|&n| instance.id(n))
+        .collect();
+      println!("clique: {} nodes ({})", result.clique.len(), ids.join(" "));
+      if result.is_certified() {
+        println!("certificate: the clique number and the chromatic number \
+          are both {}", result.clique.len());
+      }
+    }
+    Ok(())
+}
+
+/// Prints a heuristic clique size with the upper bounds of the maximum clique
+/// size given by the coloring heuristics and the degeneracy, and with an
+/// estimate of the Lovász theta number of the complement.
+pub fn bounds(config: io::BoundsConfig)
+  -> Result<(), Box<dyn std::error::Error>> {
+    let instance = io::read_file(config.filename(), config.format(),
+      config.policy(), None, false)?;
+    for d in &instance.diagnostics { eprintln!("WARNING: {}.", d); }
+    let graph = &instance.graph;
+    let clique = solver::preprocess(graph)?.incumbent.len();
+    let theta = bounds::lovasz_theta_with(graph, config.iterations());
+    println!("heuristic clique: {}", clique);
+    println!("lovasz theta estimate: {:.4} (at most {} if exact)", theta,
+      bounds::theta_clique(theta));
+    println!("coloring: {}", bounds::coloring(graph));
+    println!("degeneracy + 1: {}", bounds::degeneracy(graph));
+    Ok(())
+}
@@ -10,20 +10,37 @@ extern crate clap;
 
 use clap::App;
 use std::process;
-use max_clique::io::Config;
+use max_clique::io::{BenchConfig, BoundsConfig, ColorConfig, Config};
+use max_clique::io::ConvertConfig;
+use max_clique::io::{GenerateConfig, VerifyConfig};
 
 fn main() {
   // Read cli configuration from `cli.yml`
   let cli = load_yaml!("cli.yml");
   // Load cli from `cliconf.yml`
   let matches = App::from_yaml(cli).get_matches();
-  // Get configuration
-  let config = Config::new(matches).unwrap_or_else(|err| {
+  // Check if a subcommand must be run instead
+  let result = match matches.subcommand() {
+    ("convert", Some(matches)) => ConvertConfig::new(matches)
+      .map(max_clique::convert),
+    ("verify", Some(matches)) => VerifyConfig::new(matches)
+      .map(max_clique::verify),
+    ("bench", Some(matches)) => BenchConfig::new(matches)
+      .map(max_clique::bench),
+    ("generate", Some(matches)) => GenerateConfig::new(matches)
+      .map(max_clique::generate),
+    ("color", Some(matches)) => ColorConfig::new(matches)
+      .map(max_clique::color),
+    ("bounds", Some(matches)) => BoundsConfig::new(matches)
+      .map(max_clique::bounds),
+    _ => Config::new(&matches).map(max_clique::run),
+  };
+  // Get the result of the configuration and of the run
+  let result = result.unwrap_or_else(|err| {
     eprintln!("Problem parsing arguments: {}.", err);
     process::exit(1);
   });
-  // Run the maximum clique solver
-  if let Err(e) = max_clique::run(config) {
+  if let Err(e) = result {
     eprintln!("Application error: {}.", e);
     process::exit(1);
   }
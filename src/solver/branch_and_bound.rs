@@ -1,18 +1,22 @@
 use crate::graph::Graph;
+use super::Search;
 
 /// Solves the maximum clique problem by using a branch and bound.
-pub fn solve(graph: &Graph) -> Graph {
-  branch_and_bound(&graph, &graph.nodes_ord_by_degree(), Graph::default())
+pub fn solve(graph: &Graph, search: &mut Search) -> Graph {
+  branch_and_bound(graph, &graph.nodes_ord_by_degree(), Graph::default(),
+    search)
 }
 
-fn branch_and_bound(graph: &Graph, nodes: &[usize], mut clique: Graph)
-  -> Graph {
+fn branch_and_bound(graph: &Graph, nodes: &[usize], mut clique: Graph,
+  search: &mut Search) -> Graph {
     // Clone current solution
     let mut subgraph = clique.clone();
     // Visit all nodes
     for (i, &n) in nodes.iter().enumerate() {
       // Prune branch if the current `k`-clique subgraph cannot increase
-      if clique.degree() >= graph.degree_of(n) { break }
+      if clique.nlen() > graph.degree_of(n) { break }
+      // Stop if the time limit was reached
+      if !search.visit() { break }
       // Add node
       subgraph.insert_node(n);
       // Add edges
@@ -22,7 +26,8 @@ fn branch_and_bound(graph: &Graph, nodes: &[usize], mut clique: Graph)
         }
       }
       // Create a search branch and get the branch best solution
-      let sol = branch_and_bound(graph, &nodes[i + 1..], subgraph.clone());
+      let sol = branch_and_bound(graph, &nodes[i + 1..], subgraph.clone(),
+        search);
       // Check if the branch best solution is better than the current one
       if (sol.is_complete() && clique.is_empty()) ||
         (sol.is_complete() && sol.degree() > clique.degree()) { clique = sol; }
@@ -1,31 +1,163 @@
 mod backtracking;
 mod branch_and_bound;
+mod preprocess;
+mod weighted;
 
+use std::time::{Duration, Instant};
+use crate::error::Error;
 use crate::io::Solver;
 use crate::graph::Graph;
 
+pub use self::preprocess::{preprocess, Reduction};
+
+/// Time limit and statistics of a running search.
+pub(crate) struct Search {
+  deadline: Option<Instant>,
+  nodes: u64,
+  timed_out: bool,
+}
+
+impl Search {
+  /// Starts a search with the given time limit.
+  pub(crate) fn new(time_limit: Option<Duration>) -> Search {
+    let deadline = time_limit.and_then(|t| Instant::now().checked_add(t));
+    Search { deadline, nodes: 0, timed_out: false }
+  }
+
+  /// Counts a visited search node and returns false if the time limit was
+  /// reached, in which case the search must stop.
+  pub(crate) fn visit(&mut self) -> bool {
+    if !self.timed_out {
+      self.nodes += 1;
+      self.timed_out = self.deadline.is_some_and(|d| Instant::now() >= d);
+    }
+    !self.timed_out
+  }
+
+  /// Returns the number of visited search nodes.
+  pub(crate) fn nodes(&self) -> u64 {
+    self.nodes
+  }
+
+  /// Returns true if the time limit was reached or false otherwise.
+  pub(crate) fn timed_out(&self) -> bool {
+    self.timed_out
+  }
+}
+
+/// Result of a solver run.
+#[derive(Clone, Debug)]
+pub struct Solution {
+  /// Best clique subgraph found.
+  pub clique: Graph,
+  /// Whether the search finished, so that the clique is a maximum one.
+  pub optimal: bool,
+  /// Number of search nodes visited.
+  pub nodes: u64,
+  /// Wall time spent by the solver.
+  pub runtime: Duration,
+}
+
+impl Solution {
+  /// Returns the solution of a finished search.
+  fn new(clique: Graph, search: Search, start: Instant) -> Solution {
+    Solution { clique, optimal: !search.timed_out, nodes: search.nodes,
+      runtime: start.elapsed() }
+  }
+}
+
+/// Returns the subgraph of a graph induced by the given nodes.
+pub(crate) fn subgraph(graph: &Graph, nodes: &[usize]) -> Graph {
+  let mut subgraph = Graph::default();
+  for &n in nodes {
+    subgraph.insert_node(n);
+    for c in subgraph.nodes() {
+      if graph.adjlst_of(n).contains(&c) { subgraph.insert_edge((c, n)); }
+    }
+  }
+  subgraph
+}
+
 /// Redirects the graph to the selected solver, run it and return a maximum
 /// clique subgraph.
-pub fn solve(graph: &Graph, solver: &Solver) -> Result<Graph, &'static str> {
-  // Check if the graph is empty
-  if graph.is_empty() { return Err("the graph is empty") }
-  // If the graph has only one node return the graph
-  if graph.nlen() == 1 { return Ok(graph.clone()) }
-  // If the graph has two nodes and only one edge return the graph
-  if graph.nlen() == 2 && graph.elen() == 1 { return Ok(graph.clone()) }
-  // If the graph degree is two return a adjacent pair of nodes
-  if graph.degree() == 2 && graph.elen() <= 2 {
-    let mut solution = Graph::default();
-    let n1 = graph.nodes()[0];
-    let n2 = graph.adjlst_of(n1)[0];
-    solution.insert_node(n1);
-    solution.insert_node(n2);
-    solution.insert_edge((n1, n2));
-    return Ok(solution)
-  }
-  // Run solver and return solution
-  Ok(match solver {
-    Solver::Backtracking => backtracking::solve(&graph),
-    Solver::BranchAndBound => branch_and_bound::solve(&graph),
-  })
+pub fn solve(graph: &Graph, solver: &Solver) -> Result<Graph, Error> {
+  Ok(solve_with(graph, solver, None)?.clique)
+}
+
+/// Redirects the graph to the selected solver and runs it until it finishes
+/// or the time limit is reached, returning the best clique found with the
+/// search statistics.
+pub fn solve_with(graph: &Graph, solver: &Solver,
+  time_limit: Option<Duration>) -> Result<Solution, Error> {
+    let start = Instant::now();
+    let mut search = Search::new(time_limit);
+    // Check if the graph is empty
+    if graph.is_empty() { return Err(Error::EmptyGraph) }
+    // If the graph has only one node return the graph
+    if graph.nlen() == 1 {
+      return Ok(Solution::new(graph.clone(), search, start))
+    }
+    // If the graph has two nodes and only one edge return the graph
+    if graph.nlen() == 2 && graph.elen() == 1 {
+      return Ok(Solution::new(graph.clone(), search, start))
+    }
+    // If the graph degree is two return a adjacent pair of nodes
+    if graph.degree() == 2 && graph.elen() <= 2 {
+      let mut solution = Graph::default();
+      let n1 = graph.nodes().into_iter().find(|&n| graph.degree_of(n) > 0)
+        .expect("the graph has an edge");
+      let n2 = graph.adjlst_of(n1)[0];
+      solution.insert_node(n1);
+      solution.insert_node(n2);
+      solution.insert_edge((n1, n2));
+      return Ok(Solution::new(solution, search, start))
+    }
+    // Run solver and return solution
+    let clique = match solver {
+      Solver::Backtracking => backtracking::solve(graph, &mut search),
+      Solver::BranchAndBound => branch_and_bound::solve(graph, &mut search),
+    };
+    Ok(Solution::new(clique, search, start))
+}
+
+/// Runs the selected solver over a reduced graph until it finishes or the
+/// time limit is reached and returns the largest of the clique found and the
+/// incumbent of the reduction with the search statistics.
+pub fn solve_reduced(graph: &Graph, reduction: &Reduction, solver: &Solver,
+  time_limit: Option<Duration>) -> Result<Solution, Error> {
+    let start = Instant::now();
+    // The incumbent is a maximum clique if every node was removed
+    let mut solution = if reduction.graph.is_empty() {
+      Solution::new(Graph::default(), Search::new(None), start)
+    } else {
+      solve_with(&reduction.graph, solver, time_limit)?
+    };
+    if solution.clique.nlen() <= reduction.incumbent.len() {
+      solution.clique = subgraph(graph, &reduction.incumbent);
+    }
+    solution.runtime += reduction.runtime;
+    Ok(solution)
+}
+
+/// Runs the weighted solver and returns a maximum weight clique subgraph,
+/// where `weights[n - 1]` is the weight of the node `n`.
+pub fn solve_weighted(graph: &Graph, weights: &[f64]) -> Result<Graph, Error> {
+  Ok(solve_weighted_with(graph, weights, None)?.clique)
+}
+
+/// Runs the weighted solver until it finishes or the time limit is reached
+/// and returns the heaviest clique found with the search statistics, where
+/// `weights[n - 1]` is the weight of the node `n`.
+pub fn solve_weighted_with(graph: &Graph, weights: &[f64],
+  time_limit: Option<Duration>) -> Result<Solution, Error> {
+    let start = Instant::now();
+    let mut search = Search::new(time_limit);
+    // Check if the graph is empty
+    if graph.is_empty() { return Err(Error::EmptyGraph) }
+    // Check if all nodes have a weight
+    for n in graph.nodes() {
+      if n == 0 || n > weights.len() { return Err(Error::InvalidNode(n)) }
+    }
+    let clique = weighted::solve(graph, weights, &mut search);
+    Ok(Solution::new(clique, search, start))
 }
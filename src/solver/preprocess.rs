@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use crate::error::Error;
+use crate::graph::Graph;
+
+/// Graph shrunk before the search, with the clique found by the heuristic.
+#[derive(Clone, Debug)]
+pub struct Reduction {
+  /// Subgraph induced by the nodes that may belong to a clique larger than
+  /// the incumbent, which keep their numbers in the original graph.
+  pub graph: Graph,
+  /// Clique found by the greedy heuristic.
+  pub incumbent: Vec<usize>,
+  /// Number of nodes removed from the original graph.
+  pub removed_nodes: usize,
+  /// Number of edges removed from the original graph.
+  pub removed_edges: usize,
+  /// Wall time spent by the preprocessing.
+  pub runtime: Duration,
+}
+
+/// Returns a clique found greedily from each node, in reverse degeneracy
+/// order, by adding its adjacent nodes of largest core number first.
+fn incumbent(graph: &Graph, order: &[usize], cores: &HashMap<usize, usize>)
+  -> Vec<usize> {
+    let mut best = vec![];
+    for &n in order.iter().rev() {
+      // The core numbers decrease, so that no larger clique can be found
+      if cores[&n] < best.len() { break }
+      let mut candidates = graph.adjlst_of(n).clone();
+      candidates.sort_by_key(|c| std::cmp::Reverse(cores[c]));
+      let mut clique = vec![n];
+      for c in candidates {
+        if clique.iter().all(|&m| graph.contains_edge((c, m))) {
+          clique.push(c);
+        }
+      }
+      if clique.len() > best.len() { best = clique; }
+    }
+    best
+}
+
+/// Shrinks a graph before the search of a maximum clique.
+///
+/// A node of core number `k` belongs to no clique of more than `k + 1`
+/// nodes, so that the nodes whose core number is less than the size of the
+/// incumbent clique are removed, which peels the graph down to its core of
+/// that order. The reduction does not hold for the maximum weight clique.
+pub fn preprocess(graph: &Graph) -> Result<Reduction, Error> {
+  let start = Instant::now();
+  if graph.is_empty() { return Err(Error::EmptyGraph) }
+  let (order, cores) = graph.core_decomposition();
+  let incumbent = incumbent(graph, &order, &cores);
+  // Keep the nodes that may belong to a larger clique
+  let mut reduced = Graph::default();
+  for &n in order.iter().filter(|n| cores[n] >= incumbent.len()) {
+    reduced.insert_node(n);
+    for &an in graph.adjlst_of(n) {
+      if reduced.contains_node(an) { reduced.insert_edge((n, an)); }
+    }
+  }
+  Ok(Reduction { removed_nodes: graph.nlen() - reduced.nlen(),
+    removed_edges: graph.elen() - reduced.elen(), graph: reduced, incumbent,
+    runtime: start.elapsed() })
+}
@@ -0,0 +1,40 @@
+use crate::graph::Graph;
+use super::Search;
+
+/// Solves the maximum weight clique problem by using a branch and bound,
+/// where `weights[n - 1]` is the weight of the node `n`.
+pub fn solve(graph: &Graph, weights: &[f64], search: &mut Search) -> Graph {
+  let weight = |n: usize| weights[n - 1];
+  // Visit the heaviest nodes first
+  let mut nodes = graph.nodes();
+  nodes.sort_by(|&a, &b| weight(b).total_cmp(&weight(a)));
+  let mut best = (vec![], f64::NEG_INFINITY);
+  branch_and_bound(graph, &weight, &nodes, &mut vec![], 0.0, &mut best,
+    search);
+  // Build the clique subgraph
+  super::subgraph(graph, &best.0)
+}
+
+fn branch_and_bound<F: Fn(usize) -> f64>(graph: &Graph, weight: &F,
+  candidates: &[usize], clique: &mut Vec<usize>, total: f64,
+  best: &mut (Vec<usize>, f64), search: &mut Search) {
+    // Check if the current clique is better than the best one
+    if total > best.1 { *best = (clique.clone(), total); }
+    for (i, &n) in candidates.iter().enumerate() {
+      // Prune branch if the remaining nodes cannot increase the weight
+      let bound: f64 = candidates[i..].iter().map(|&c| weight(c).max(0.0))
+        .sum();
+      if total + bound <= best.1 { break }
+      // Stop if the time limit was reached
+      if !search.visit() { break }
+      // Add node and keep only the candidates adjacent to it
+      let adjlst = graph.adjlst_of(n);
+      let next: Vec<usize> = candidates[i + 1..].iter()
+        .filter(|c| adjlst.contains(c)).copied().collect();
+      clique.push(n);
+      branch_and_bound(graph, weight, &next, clique, total + weight(n), best,
+        search);
+      // Remove added node
+      clique.pop();
+    }
+}
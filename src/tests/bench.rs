@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::bench::{self, Row};
+use crate::io::{dimacs, Policy, ReportFormat, Solver};
+
+/// Returns the measurements of a run.
+fn row() -> Row {
+  Row { instance: "I9.clq".to_string(), nodes: 20, edges: 31,
+    solver: "BranchAndBound".to_string(), size: 4, runtime: 0.5,
+    min_runtime: 0.25, search_nodes: 100, timeouts: 1, repetitions: 2 }
+}
+
+#[test]
+fn bench_instances() {
+  let paths = bench::instances("db").unwrap();
+  assert_eq!(paths.len(), 28);
+  assert!(paths.contains(&PathBuf::from("db/C125.9.clq")));
+  let paths = bench::instances("db/I1*.clq").unwrap();
+  assert_eq!(paths.len(), 11);
+  assert_eq!(paths[0], PathBuf::from("db/I1.clq"));
+  let paths = bench::instances("db/I0.clq").unwrap();
+  assert_eq!(paths, vec![PathBuf::from("db/I0.clq")]);
+  assert!(bench::instances("db/missing*").unwrap().is_empty());
+  assert!(bench::instances("db/[").is_err());
+}
+
+#[test]
+fn bench_run() {
+  let content = fs::read_to_string("db/I8.clq").unwrap();
+  let instance = dimacs::parse(&content, Policy::Strict).unwrap();
+  let row = bench::run("I8.clq", &instance, Solver::BranchAndBound, None, 3)
+    .unwrap();
+  assert_eq!((row.nodes, row.edges, row.size), (12, 21, 5));
+  assert_eq!((row.timeouts, row.repetitions), (0, 3));
+  assert!(row.search_nodes > 0);
+  assert!(row.min_runtime <= row.runtime);
+  let row = bench::run("I8.clq", &instance, Solver::Backtracking,
+    Some(Duration::ZERO), 2).unwrap();
+  assert_eq!(row.timeouts, 2);
+}
+
+#[test]
+fn bench_write() {
+  let rows = vec![row()];
+  let mut bytes = vec![];
+  bench::write_table(&mut bytes, &rows).unwrap();
+  let text = String::from_utf8(bytes).unwrap();
+  let lines: Vec<&str> = text.lines().collect();
+  assert_eq!(lines[0], "instance  nodes  edges  solver          size  \
+    time (s)  search nodes  timeouts");
+  assert_eq!(lines[1], format!("I9.clq       20     31  BranchAndBound     \
+    4  0.500000           100  {:>8}", "1/2"));
+  let mut bytes = vec![];
+  bench::write(&mut bytes, ReportFormat::Csv, &rows).unwrap();
+  assert_eq!(String::from_utf8(bytes).unwrap().lines().nth(1),
+    Some("I9.clq,20,31,BranchAndBound,4,0.5,0.25,100,1,2"));
+  let mut bytes = vec![];
+  bench::write(&mut bytes, ReportFormat::Json, &rows).unwrap();
+  let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+  assert_eq!(json[0]["timeouts"], 1);
+  assert_eq!(json[0]["solver"], "BranchAndBound");
+}
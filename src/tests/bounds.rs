@@ -0,0 +1,100 @@
+use std::fs;
+use crate::bounds;
+use crate::graph::Graph;
+use crate::io::{dimacs, Policy, Solver};
+use crate::solver;
+use super::cycle;
+
+/// Returns the Petersen graph.
+fn petersen() -> Graph {
+  let mut graph = Graph::new(10);
+  for a in 0..5 {
+    graph.insert_edge((a + 1, (a + 1) % 5 + 1));
+    graph.insert_edge((a + 6, (a + 2) % 5 + 6));
+    graph.insert_edge((a + 1, a + 6));
+  }
+  graph
+}
+
+#[test]
+fn lovasz_theta_known_values() {
+  // The theta number of the complement of the 5-cycle is sqrt(5)
+  let theta = bounds::lovasz_theta(&cycle(5));
+  assert!((5f64.sqrt() - 1e-9..5f64.sqrt() + 0.01).contains(&theta));
+  // The theta number of the complement of the Petersen graph is 10/4
+  let theta = bounds::lovasz_theta(&petersen());
+  assert!((2.5 - 1e-9..2.51).contains(&theta));
+  // The bound is exact for complete and edgeless graphs
+  let mut graph = Graph::new(6);
+  for a in 1..=6 {
+    for b in a + 1..=6 { graph.insert_edge((a, b)); }
+  }
+  assert!((bounds::lovasz_theta(&graph) - 6.0).abs() < 1e-9);
+  assert!((bounds::lovasz_theta(&Graph::new(4)) - 1.0).abs() < 1e-9);
+  assert_eq!(bounds::lovasz_theta(&Graph::default()), 0.0);
+}
+
+#[test]
+fn lovasz_theta_sparse() {
+  // The free entries of the pairs of nodes of a large sparse graph are not
+  // stored one by one
+  let mut graph = Graph::new(40000);
+  for a in (1..=40000).step_by(2) { graph.insert_edge((a, a + 1)); }
+  let theta = bounds::lovasz_theta_with(&graph, 5);
+  assert!((theta - 2.0).abs() < 1e-6, "{}", theta);
+}
+
+#[test]
+fn lovasz_theta_iterations() {
+  // The estimates stay above the theta number and do not increase
+  let graph = petersen();
+  let mut last = f64::INFINITY;
+  for &iterations in &[0, 5, 20, 100] {
+    let theta = bounds::lovasz_theta_with(&graph, iterations);
+    assert!(theta >= 2.5 - 1e-9 && theta <= last, "{}", iterations);
+    last = theta;
+  }
+  assert!((bounds::lovasz_theta_with(&graph, 0) - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn max_eigen_steps() {
+  // The largest eigenvalues of the identity plus the adjacency matrix of a
+  // long cycle are close, so that a few Lanczos steps do not converge
+  let matrix = bounds::Matrix::new(&cycle(50));
+  let start: Vec<f64> = (0..50).map(|i| 1.0 + 0.1 * (i as f64).sin())
+    .collect();
+  let eigen = matrix.max_eigen(&start, 2);
+  assert!(!eigen.converged());
+  assert!(eigen.value < 3.0);
+  let eigen = matrix.max_eigen(&start, bounds::STEPS);
+  assert!(eigen.converged());
+  assert!((eigen.value + eigen.residual - 3.0).abs() < 1e-9);
+  // The edgeless graph converges at once
+  let eigen = bounds::Matrix::new(&Graph::new(4)).max_eigen(&start[..4], 1);
+  assert!(eigen.converged());
+  assert!((eigen.value - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn bounds_instances() {
+  for name in &["I0", "I5", "I9", "I22"] {
+    let content = fs::read_to_string(format!("db/{}.clq", name)).unwrap();
+    let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+    let size = solver::solve(&graph, &Solver::BranchAndBound).unwrap().nlen();
+    let theta = bounds::lovasz_theta_with(&graph, 50);
+    assert!(bounds::theta_clique(theta) >= size, "{}: {}", name, theta);
+    assert!(bounds::coloring(&graph) >= size, "{}", name);
+    assert!(bounds::degeneracy(&graph) >= size, "{}", name);
+  }
+}
+
+#[test]
+fn theta_clique() {
+  assert_eq!(bounds::theta_clique(4.0277), 4);
+  assert_eq!(bounds::theta_clique(2.9999999999), 3);
+  assert_eq!(bounds::theta_clique(0.0), 0);
+  assert_eq!(bounds::degeneracy(&Graph::default()), 0);
+  assert_eq!(bounds::degeneracy(&cycle(4)), 3);
+  assert_eq!(bounds::coloring(&cycle(5)), 3);
+}
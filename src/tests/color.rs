@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::fs;
+use crate::Error;
+use crate::color::{self, Algorithm, Coloring};
+use crate::graph::Graph;
+use crate::io::{dimacs, Policy};
+use super::cycle;
+
+#[test]
+fn color_cycles() {
+  for &algorithm in &Algorithm::ALL {
+    assert_eq!(color::color(&cycle(6), algorithm).count(), 2);
+    assert_eq!(color::color(&cycle(7), algorithm).count(), 3);
+    assert_eq!(color::color(&Graph::new(3), algorithm).count(), 1);
+    assert_eq!(color::color(&Graph::default(), algorithm).count(), 0);
+  }
+}
+
+#[test]
+fn color_instances() {
+  for name in &["I0", "I5", "I9", "I22", "C125.9"] {
+    let content = fs::read_to_string(format!("db/{}.clq", name)).unwrap();
+    let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+    for &algorithm in &Algorithm::ALL {
+      let coloring = color::color(&graph, algorithm);
+      let checked = Coloring::new(&graph, coloring.colors().clone()).unwrap();
+      assert_eq!(checked, coloring, "{} {}", name, algorithm);
+      let classes = coloring.classes();
+      assert_eq!(classes.len(), coloring.count());
+      assert!(classes.iter().all(|c| !c.is_empty()));
+    }
+    // Smallest last uses at most one color more than the degeneracy
+    assert!(color::smallest_last(&graph).count() <= graph.degeneracy() + 1);
+  }
+}
+
+#[test]
+fn greedy_order() {
+  let graph = cycle(4);
+  let coloring = color::greedy(&graph, &[1, 3, 2, 4]).unwrap();
+  assert_eq!(coloring.count(), 2);
+  assert_eq!(coloring.color_of(3), Some(0));
+  assert_eq!(coloring.color_of(5), None);
+  // A bad order for a bipartite graph
+  let mut graph = Graph::new(6);
+  for &e in &[(1, 4), (1, 6), (2, 5), (2, 6), (3, 4), (3, 5)] {
+    graph.insert_edge(e);
+  }
+  assert_eq!(color::greedy(&graph, &[1, 5, 2, 4, 3, 6]).unwrap().count(), 3);
+  match color::greedy(&graph, &[1, 2, 3, 4, 5]) {
+    Err(Error::InvalidNode(6)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  match color::greedy(&graph, &[1, 1, 2, 3, 4, 5, 6]) {
+    Err(Error::DuplicateNode(1)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  match color::greedy(&graph, &[7]) {
+    Err(Error::InvalidNode(7)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn coloring_invalid() {
+  let graph = cycle(3);
+  let colors: HashMap<usize, usize> = [(1, 0), (2, 1)].iter().copied()
+    .collect();
+  match Coloring::new(&graph, colors) {
+    Err(Error::InvalidNode(3)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  let colors = [(1, 0), (2, 1), (3, 1)].iter().copied().collect();
+  match Coloring::new(&graph, colors) {
+    Err(Error::ColorConflict(2, 3)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  let colors = [(1, 0), (2, 1), (3, 2), (4, 0)].iter().copied().collect();
+  match Coloring::new(&graph, colors) {
+    Err(Error::InvalidNode(4)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  let colors = [(1, 0), (2, 1), (3, 4)].iter().copied().collect();
+  assert_eq!(Coloring::new(&graph, colors).unwrap().count(), 5);
+}
+
+#[test]
+fn algorithm_names() {
+  for &algorithm in &Algorithm::ALL {
+    assert_eq!(algorithm.to_string().parse(), Ok(algorithm));
+  }
+  assert!("rlf".parse::<Algorithm>().is_err());
+}
+
+/// Returns the Grötzsch graph, which has no triangle and needs four colors.
+fn grotzsch() -> Graph {
+  let mut graph = cycle(5);
+  // Each node of the cycle is shadowed by a node adjacent to its neighbors,
+  // which are adjacent to a last node
+  for a in 1..=5 {
+    graph.insert_node(a + 5);
+    graph.insert_edge((a + 5, a % 5 + 1));
+    graph.insert_edge((a + 5, (a + 3) % 5 + 1));
+  }
+  graph.insert_node(11);
+  for a in 6..=10 { graph.insert_edge((a, 11)); }
+  graph
+}
+
+#[test]
+fn chromatic_number() {
+  let result = color::chromatic_number(&cycle(5), None).unwrap();
+  assert!(result.optimal);
+  assert_eq!(result.coloring.count(), 3);
+  assert_eq!(result.clique.len(), 2);
+  assert!(!result.is_certified());
+  let graph = grotzsch();
+  assert_eq!(graph.elen(), 20);
+  let result = color::chromatic_number(&graph, None).unwrap();
+  assert!(result.optimal);
+  assert!(result.nodes > 0);
+  assert_eq!(result.coloring.count(), 4);
+  assert_eq!(result.clique.len(), 2);
+  let checked = Coloring::new(&graph, result.coloring.colors().clone());
+  assert_eq!(checked.unwrap(), result.coloring);
+  assert!(color::chromatic_number(&Graph::default(), None).is_err());
+}
+
+#[test]
+fn chromatic_number_certificate() {
+  let content = fs::read_to_string("db/I9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let result = color::chromatic_number(&graph, None).unwrap();
+  assert!(result.optimal);
+  assert!(result.is_certified());
+  assert_eq!(result.coloring.count(), 4);
+  let verification = crate::verify::verify(&graph, &result.clique).unwrap();
+  assert!(verification.is_clique());
+}
+
+#[test]
+fn chromatic_number_deep_search() {
+  // DSATUR needs a color more than the least coloring of these edges, so
+  // the search colors every isolated node, which is deeper than the small
+  // thread stack allows for a recursive search
+  let mut graph = Graph::new(3000);
+  for &e in &[(1, 2), (1, 4), (1, 5), (2, 5), (2, 7), (2, 9), (3, 5), (3, 7),
+    (4, 7), (4, 10), (5, 6), (5, 8), (5, 9), (7, 10), (9, 10)] {
+      graph.insert_edge(e);
+  }
+  assert_eq!(color::dsatur(&graph).count(), 4);
+  let search = std::thread::Builder::new().stack_size(64 * 1024)
+    .spawn(move || color::chromatic_number(&graph, None).unwrap()).unwrap();
+  let result = search.join().unwrap();
+  assert!(result.optimal);
+  assert_eq!(result.coloring.count(), 3);
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use crate::Error;
+use crate::generate::{self, Model};
+use crate::io::Solver;
+use crate::{solver, verify};
+
+#[test]
+fn generate_gnp() {
+  let model = Model::Gnp { nodes: 30, p: 0.5 };
+  let a = generate::generate(&model, 1).unwrap();
+  let b = generate::generate(&model, 1).unwrap();
+  let c = generate::generate(&model, 2).unwrap();
+  assert_eq!(a.graph, b.graph);
+  assert_ne!(a.graph, c.graph);
+  assert_eq!(a.graph.nlen(), 30);
+  assert!(a.clique.is_none());
+  let empty = generate::generate(&Model::Gnp { nodes: 10, p: 0.0 }, 1)
+    .unwrap();
+  assert_eq!(empty.graph.elen(), 0);
+  let complete = generate::generate(&Model::Gnp { nodes: 10, p: 1.0 }, 1)
+    .unwrap();
+  assert!(complete.graph.is_complete());
+}
+
+#[test]
+fn generate_gnm() {
+  for &edges in &[0, 1, 17, 44, 45] {
+    let model = Model::Gnm { nodes: 10, edges };
+    let graph = generate::generate(&model, 3).unwrap().graph;
+    assert_eq!(graph.elen(), edges);
+    assert_eq!(graph.nlen(), 10);
+  }
+  assert!(matches!(generate::generate(&Model::Gnm { nodes: 10, edges: 46 },
+    3), Err(Error::InvalidParameter(_))));
+}
+
+#[test]
+fn generate_planted() {
+  let model = Model::Planted { nodes: 20, p: 0.2, clique: 8 };
+  let generated = generate::generate(&model, 5).unwrap();
+  assert!(generated.maximum);
+  let clique = generated.clique.unwrap();
+  assert_eq!(clique.len(), 8);
+  let result = verify::verify(&generated.graph, &clique).unwrap();
+  assert!(result.is_maximal());
+  let solution = solver::solve(&generated.graph, &Solver::BranchAndBound)
+    .unwrap();
+  assert_eq!(solution.nlen(), 8);
+}
+
+#[test]
+fn generate_planted_small() {
+  // A small planted clique is not known to be maximum
+  let model = Model::Planted { nodes: 20, p: 0.5, clique: 3 };
+  let generated = generate::generate(&model, 5).unwrap();
+  assert_eq!(generated.clique.as_ref().unwrap().len(), 3);
+  assert_eq!(generated.maximum_clique(), None);
+  let solution = solver::solve(&generated.graph, &Solver::BranchAndBound)
+    .unwrap();
+  assert!(solution.nlen() > 3);
+  let model = Model::Planted { nodes: 10, p: 1.0, clique: 3 };
+  assert!(!generate::generate(&model, 5).unwrap().maximum);
+  let model = Model::Planted { nodes: 10, p: 0.0, clique: 3 };
+  let generated = generate::generate(&model, 5).unwrap();
+  assert_eq!(generated.maximum_clique().unwrap().len(), 3);
+}
+
+#[test]
+fn generate_brockington() {
+  let model = Model::Brockington { nodes: 200, p: 0.5, clique: 20 };
+  let generated = generate::generate(&model, 9).unwrap();
+  let graph = &generated.graph;
+  let clique = generated.clique.unwrap();
+  assert!(verify::verify(graph, &clique).unwrap().is_clique());
+  // The clique nodes have about the same degree as the others
+  let mean = |nodes: &[usize]| nodes.iter()
+    .map(|&n| graph.degree_of(n) as f64).sum::<f64>() / nodes.len() as f64;
+  let others: Vec<usize> = graph.nodes().into_iter()
+    .filter(|n| !clique.contains(n)).collect();
+  assert!((mean(&clique) - 99.5).abs() < 10.0);
+  assert!((mean(&others) - 99.5).abs() < 10.0);
+  let model = Model::Brockington { nodes: 20, p: 0.1, clique: 10 };
+  assert!(generate::generate(&model, 9).is_err());
+}
+
+#[test]
+fn generate_invalid() {
+  let model = Model::Gnp { nodes: 0, p: 0.5 };
+  assert!(matches!(generate::generate(&model, 0), Err(Error::EmptyGraph)));
+  let model = Model::Gnp { nodes: 10, p: 1.5 };
+  assert!(generate::generate(&model, 0).is_err());
+  let model = Model::Planted { nodes: 10, p: 0.5, clique: 11 };
+  assert!(generate::generate(&model, 0).is_err());
+  // The hidden cliques have at least one node
+  let model = Model::Planted { nodes: 10, p: 0.5, clique: 0 };
+  let result = generate::generate(&model, 0);
+  assert!(matches!(result, Err(Error::InvalidParameter(_))));
+  let model = Model::Brockington { nodes: 10, p: 0.5, clique: 0 };
+  let result = generate::generate(&model, 0);
+  assert!(matches!(result, Err(Error::InvalidParameter(_))));
+  assert_eq!(Model::Planted { nodes: 10, p: 0.5, clique: 3 }.to_string(),
+    "planted n=10 p=0.5 k=3");
+}
+
+#[test]
+fn generate_dimacs_families() {
+  // Sizes of the DIMACS instances
+  let cases = [(Model::Hamming { length: 6, distance: 2 }, 64, 1824),
+    (Model::Hamming { length: 8, distance: 4 }, 256, 20864),
+    (Model::Johnson { length: 8, weight: 2, distance: 4 }, 28, 210),
+    (Model::Johnson { length: 16, weight: 2, distance: 4 }, 120, 5460),
+    (Model::Johnson { length: 32, weight: 2, distance: 4 }, 496, 107880),
+    (Model::Johnson { length: 40, weight: 2, distance: 4 }, 780, 274170),
+    (Model::Mann { points: 9 }, 45, 918),
+    (Model::Mann { points: 27 }, 378, 70551),
+    (Model::Mann { points: 45 }, 1035, 533115),
+    (Model::Keller { dimension: 4 }, 171, 9435),
+    (Model::Keller { dimension: 5 }, 776, 225990)];
+  for (model, nodes, edges) in &cases {
+    let graph = generate::generate(model, 0).unwrap().graph;
+    assert_eq!((graph.nlen(), graph.elen()), (*nodes, *edges), "{}", model);
+    assert_eq!(model.nodes(), Some(*nodes), "{}", model);
+  }
+  assert_eq!(Model::Mann { points: 9 }.to_string(), "MANN_a9");
+  assert_eq!(Model::Keller { dimension: 6 }.nodes(), Some(3361));
+  assert_eq!(Model::Keller { dimension: 4 }.to_string(), "keller4");
+  let graph = generate::generate(&Model::Johnson { length: 5, weight: 0,
+    distance: 1 }, 0).unwrap().graph;
+  assert_eq!(graph.nlen(), 1);
+  assert!(generate::generate(&Model::Hamming { length: 64, distance: 1 }, 0)
+    .is_err());
+}
+
+#[test]
+fn generate_random_families() {
+  let model = Model::BarabasiAlbert { nodes: 100, degree: 3 };
+  let graph = generate::generate(&model, 4).unwrap().graph;
+  assert_eq!(graph.elen(), 6 + 3 * 96);
+  assert!(graph.nodes().iter().all(|&n| graph.degree_of(n) >= 3));
+  let model = Model::WattsStrogatz { nodes: 30, degree: 4, beta: 0.3 };
+  let graph = generate::generate(&model, 4).unwrap().graph;
+  assert_eq!(graph.elen(), 60);
+  let model = Model::WattsStrogatz { nodes: 30, degree: 4, beta: 0.0 };
+  let graph = generate::generate(&model, 4).unwrap().graph;
+  assert!(graph.nodes().iter().all(|&n| graph.degree_of(n) == 4));
+  assert!(graph.contains_edge((30, 2)));
+  let model = Model::Geometric { nodes: 20, radius: 1.5 };
+  assert!(generate::generate(&model, 4).unwrap().graph.is_complete());
+  let model = Model::Geometric { nodes: 20, radius: 0.0 };
+  assert_eq!(generate::generate(&model, 4).unwrap().graph.elen(), 0);
+  // Check the invalid parameters
+  for model in &[Model::BarabasiAlbert { nodes: 5, degree: 5 },
+    Model::WattsStrogatz { nodes: 10, degree: 3, beta: 0.5 },
+    Model::WattsStrogatz { nodes: 10, degree: 4, beta: 1.5 }] {
+      assert!(matches!(generate::generate(model, 0),
+        Err(Error::InvalidParameter(_))), "{}", model);
+  }
+}
+
+#[test]
+fn generate_steiner_triples() {
+  for points in (1..100).filter(|p| p % 6 == 1 || p % 6 == 3) {
+    let triples = generate::steiner_triples(points).unwrap();
+    assert_eq!(triples.len(), points * (points - 1) / 6, "{}", points);
+    // Each pair of points lies in exactly one triple
+    let mut pairs = HashSet::new();
+    for &[x, y, z] in &triples {
+      assert!(x < points && y < points && z < points, "{}", points);
+      for e in [(x, y), (x, z), (y, z)] {
+        assert!(pairs.insert((e.0.min(e.1), e.0.max(e.1))), "{}", points);
+      }
+    }
+  }
+  for points in [0, 2, 5, 11] {
+    assert!(generate::steiner_triples(points).is_err(), "{}", points);
+    assert!(generate::generate(&Model::Mann { points }, 0).is_err());
+  }
+}
@@ -1,3 +1,4 @@
+use crate::Error;
 use crate::graph::*;
 
 #[test]
@@ -6,7 +7,7 @@ fn default_graph() {
   assert_eq!(graph.is_empty(), true);
   assert_eq!(graph.degree(), 0);
   let mut nodes = graph.nodes(); nodes.sort();
-  assert_eq!(nodes, vec![]);
+  assert_eq!(nodes, Vec::<usize>::new());
 }
 
 #[test]
@@ -121,7 +122,7 @@ fn adjacency_list() {
   assert_eq!(*graph.adjlst_of(1), vec![2, 3]);
   assert_eq!(*graph.adjlst_of(2), vec![1]);
   assert_eq!(*graph.adjlst_of(3), vec![1]);
-  assert_eq!(*graph.adjlst_of(4), vec![]);
+  assert_eq!(*graph.adjlst_of(4), Vec::<usize>::new());
 }
 
 #[test]
@@ -130,7 +131,7 @@ fn invalid_adjacency_list() {
   let mut graph = Graph::new(3);
   graph.insert_edge((1, 2));
   graph.insert_edge((2, 3));
-  assert_eq!(*graph.adjlst_of(1), vec![]);
+  assert_eq!(*graph.adjlst_of(1), Vec::<usize>::new());
   assert_eq!(*graph.adjlst_of(1), vec![2, 3]);
 }
 
@@ -145,7 +146,7 @@ fn insert_node_i() {
   let mut nodes = graph.nodes(); nodes.sort();
   assert_eq!(nodes, vec![1, 2, 3]);
   graph.insert_node(4);
-  assert_eq!(*graph.adjlst_of(4), vec![]);
+  assert_eq!(*graph.adjlst_of(4), Vec::<usize>::new());
   let mut nodes = graph.nodes(); nodes.sort();
   assert_eq!(nodes, vec![1, 2, 3, 4]);
 }
@@ -203,8 +204,8 @@ fn remove_node_ii() {
   let mut nodes = graph.nodes(); nodes.sort();
   assert_eq!(nodes, vec![1, 2, 3]);
   graph.remove_node(1);
-  assert_eq!(*graph.adjlst_of(2), vec![]);
-  assert_eq!(*graph.adjlst_of(3), vec![]);
+  assert_eq!(*graph.adjlst_of(2), Vec::<usize>::new());
+  assert_eq!(*graph.adjlst_of(3), Vec::<usize>::new());
   let mut nodes = graph.nodes(); nodes.sort();
   assert_eq!(nodes, vec![2, 3]);
 }
@@ -228,7 +229,7 @@ fn remove_edge() {
   assert_eq!(edges, vec![(1, 2), (1, 3)]);
   graph.remove_edge((1, 2));
   assert_eq!(*graph.adjlst_of(1), vec![3]);
-  assert_eq!(*graph.adjlst_of(2), vec![]);
+  assert_eq!(*graph.adjlst_of(2), Vec::<usize>::new());
   assert_eq!(*graph.adjlst_of(3), vec![1]);
   let mut edges = graph.edges(); edges.sort();
   assert_eq!(edges, vec![(1, 3)]);
@@ -279,3 +280,106 @@ fn is_not_complete_ii() {
   graph.insert_edge((2, 3));
   assert!(graph.is_complete());
 }
+
+#[test]
+fn try_insert_edge() {
+  let mut graph = Graph::new(2);
+  assert!(graph.try_insert_edge((1, 2)).is_ok());
+  match graph.try_insert_edge((1, 3)) {
+    Err(Error::InvalidNode(3)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  assert_eq!(*graph.adjlst_of(1), vec![2]);
+  assert_eq!(graph.elen(), 1);
+}
+
+#[test]
+fn try_insert_node() {
+  let mut graph = Graph::new(2);
+  assert!(graph.try_insert_node(3).is_ok());
+  match graph.try_insert_node(3) {
+    Err(Error::DuplicateNode(3)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn try_remove_node() {
+  let mut graph = Graph::new(2);
+  assert!(graph.try_remove_node(2).is_ok());
+  match graph.try_remove_node(2) {
+    Err(Error::InvalidNode(2)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn try_remove_edge() {
+  let mut graph = Graph::new(3);
+  graph.insert_edge((1, 2));
+  match graph.try_remove_edge((1, 3)) {
+    Err(Error::InvalidEdge(1, 3)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  assert!(graph.try_remove_edge((2, 1)).is_ok());
+  assert_eq!(graph.elen(), 0);
+}
+
+#[test]
+fn core_decomposition() {
+  // A 4-clique with a triangle and a pendant node attached to it
+  let mut graph = Graph::new(8);
+  for &e in &[(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4), (4, 5), (4, 6),
+    (5, 6), (6, 7)] {
+      graph.insert_edge(e);
+  }
+  graph.insert_node(10);
+  let cores = graph.core_numbers();
+  let expected = [(1, 3), (2, 3), (3, 3), (4, 3), (5, 2), (6, 2), (7, 1),
+    (8, 0), (10, 0)];
+  for &(n, core) in &expected { assert_eq!(cores[&n], core, "node {}", n); }
+  // The core numbers do not decrease along the order
+  let order = graph.nodes_ord_by_degeneracy();
+  assert_eq!(order.len(), 9);
+  assert!(order.windows(2).all(|w| cores[&w[0]] <= cores[&w[1]]));
+  assert_eq!(graph.degeneracy(), 3);
+}
+
+#[test]
+fn degeneracy() {
+  assert_eq!(Graph::default().degeneracy(), 0);
+  assert_eq!(Graph::new(3).degeneracy(), 0);
+  let mut graph = Graph::new(5);
+  for a in 1..=5 {
+    for b in a + 1..=5 { graph.insert_edge((a, b)); }
+  }
+  assert_eq!(graph.degeneracy(), 4);
+  // A cycle is 2-degenerate
+  let mut graph = Graph::new(6);
+  for a in 1..=6 { graph.insert_edge((a, a % 6 + 1)); }
+  assert_eq!(graph.degeneracy(), 2);
+  assert!(graph.core_numbers().values().all(|&c| c == 2));
+}
+
+#[test]
+fn remove_updates_degree() {
+  let mut graph = Graph::new(5);
+  graph.insert_edge((1, 2));
+  graph.insert_edge((1, 3));
+  graph.insert_edge((4, 2));
+  graph.insert_edge((4, 3));
+  graph.insert_edge((2, 3));
+  assert_eq!(graph.degree(), 3);
+  // Another node keeps the graph degree
+  graph.remove_edge((1, 2));
+  assert_eq!(graph.degree(), 3);
+  graph.remove_edge((2, 3));
+  assert_eq!(graph.degree(), 2);
+  graph.remove_node(4);
+  assert_eq!(graph.degree(), 1);
+  graph.remove_node(3);
+  assert_eq!(graph.degree(), 0);
+  graph.insert_node(6);
+  graph.insert_edge((5, 6));
+  assert_eq!(graph.degree(), 1);
+}
@@ -0,0 +1,733 @@
+use std::fs;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+use bzip2::write::BzEncoder;
+use flate2::write::GzEncoder;
+use xz2::write::XzEncoder;
+use crate::Error;
+use crate::graph::Graph;
+use crate::io::{self, adjlist, binary, dimacs, dot, edgelist, gexf, graphml};
+use crate::io::{metis, mtx, solution};
+use crate::io::{Compression, Format, Policy, Progress, Report};
+use crate::io::{Overwrite, ReportFormat, Solver};
+use crate::solver;
+
+#[test]
+fn dimacs_parse() {
+  let content = "c comment\np col 3 2\ne 1 2\n\ne 2 3\n";
+  let instance = dimacs::parse(content, Policy::Strict).unwrap();
+  let (graph, diagnostics) = (instance.graph, instance.diagnostics);
+  assert_eq!(graph.nlen(), 3);
+  let mut edges = graph.edges(); edges.sort();
+  assert_eq!(edges, vec![(1, 2), (2, 3)]);
+  assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn dimacs_parse_edge_format() {
+  let content = "p edge 2 1\ne 2 1\n";
+  let graph = dimacs::parse(content, Policy::Strict).unwrap().graph;
+  assert!(graph.contains_edge((1, 2)));
+}
+
+#[test]
+fn dimacs_parse_db() {
+  for entry in fs::read_dir("db").unwrap() {
+    let path = entry.unwrap().path();
+    if path.extension().is_none_or(|e| e != "clq") { continue; }
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(dimacs::parse(&content, Policy::Strict).is_ok(), "{:?}", path);
+  }
+}
+
+#[test]
+fn dimacs_invalid_header() {
+  for content in &["p col 3\n", "p clique 3 0\n", "p col x 0\n"] {
+    match dimacs::parse(content, Policy::Lenient) {
+      Err(Error::Parse { line: 1, .. }) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+  match dimacs::parse("c comment\n", Policy::Lenient) {
+    Err(Error::Parse { .. }) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  match dimacs::parse("p col 0 0\n", Policy::Lenient) {
+    Err(Error::EmptyGraph) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  // The number of nodes is limited before the graph is allocated
+  let content = format!("p col {} 0\n", io::MAX_NODES + 1);
+  match dimacs::parse(&content, Policy::Lenient) {
+    Err(Error::Parse { line: 1, column: 7, .. }) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  match dimacs::parse("p edge 16777216 0\n", Policy::Lenient) {
+    Err(Error::Parse { line: 1, column: 8, .. }) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn dimacs_out_of_range() {
+  match dimacs::parse("p col 2 1\ne 1  3\n", Policy::Lenient) {
+    Err(Error::Parse { line: 2, column: 6, .. }) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn dimacs_strict() {
+  let contents = ["p col 2 2\ne 1 2\ne 2 1\n", "p col 2 1\ne 1 1\n",
+    "p col 2 1\ne 1 2\nn 1 3\n", "p col 2 2\ne 1 2\n"];
+  for content in &contents {
+    match dimacs::parse(content, Policy::Strict) {
+      Err(Error::Parse { .. }) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+}
+
+#[test]
+fn dimacs_lenient() {
+  let content = "p col 3 4\ne 1 2\ne 2 1\ne 3 3\ne 2 3\n";
+  let instance = dimacs::parse(content, Policy::Lenient).unwrap();
+  let (graph, diagnostics) = (instance.graph, instance.diagnostics);
+  assert_eq!(graph.elen(), 2);
+  assert_eq!(graph.degree_of(1), 1);
+  assert_eq!(graph.degree_of(3), 1);
+  let lines: Vec<usize> = diagnostics.iter().map(|d| d.line).collect();
+  assert_eq!(lines, vec![3, 4]);
+}
+
+#[test]
+fn compression_from_path() {
+  assert_eq!(Compression::from_path("db/I1.clq"), Compression::None);
+  assert_eq!(Compression::from_path("db/I1.clq.gz"), Compression::Gzip);
+  assert_eq!(Compression::from_path("db/I1.clq.bz2"), Compression::Bzip2);
+  assert_eq!(Compression::from_path("db/I1.clq.xz"), Compression::Xz);
+}
+
+#[test]
+fn read_from_plain() {
+  let content = fs::read("db/I1.clq").unwrap();
+  let reader = io::decompress(Cursor::new(content), Compression::None)
+    .unwrap();
+  let graph = io::read_from(reader, Some(Format::Dimacs),
+    Policy::Strict, None).unwrap().graph;
+  assert!(graph.is_complete());
+  assert_eq!(graph.nlen(), 3);
+}
+
+#[test]
+fn read_from_compressed() {
+  let content = fs::read("db/I1.clq").unwrap();
+  let mut gz = GzEncoder::new(vec![], flate2::Compression::default());
+  gz.write_all(&content).unwrap();
+  let mut bz = BzEncoder::new(vec![], bzip2::Compression::default());
+  bz.write_all(&content).unwrap();
+  let mut xz = XzEncoder::new(vec![], 6);
+  xz.write_all(&content).unwrap();
+  let compressed = [gz.finish().unwrap(), bz.finish().unwrap(),
+    xz.finish().unwrap()];
+  for bytes in compressed.iter() {
+    // The compression is detected by magic bytes regardless of the fallback
+    let reader = io::decompress(Cursor::new(bytes.clone()), Compression::None)
+      .unwrap();
+    let graph = io::read_from(reader, Some(Format::Dimacs),
+      Policy::Strict, None).unwrap().graph;
+    assert!(graph.is_complete());
+    assert_eq!(graph.nlen(), 3);
+  }
+}
+
+#[test]
+fn read_from_progress() {
+  let content = fs::read("db/C125.9.clq").unwrap();
+  let mut progress = Progress::new(Cursor::new(content.clone()),
+    Some(content.len() as u64));
+  let mut read = vec![];
+  progress.read_to_end(&mut read).unwrap();
+  assert_eq!(read, content);
+  let progress = Progress::new(Cursor::new(content), None);
+  let graph = io::read_from(progress, Some(Format::Dimacs),
+    Policy::Strict, None).unwrap().graph;
+  assert_eq!(graph.nlen(), 125);
+  assert_eq!(graph.elen(), 6963);
+}
+
+#[test]
+fn format_from_path() {
+  assert_eq!(Format::from_path("db/I1.clq"), Some(Format::Dimacs));
+  assert_eq!(Format::from_path("db/I1.clq.gz"), Some(Format::Dimacs));
+  assert_eq!(Format::from_path("db/I1.clq.b"), Some(Format::DimacsBinary));
+  assert_eq!(Format::from_path("db/I1.clq.b.xz"),
+    Some(Format::DimacsBinary));
+  assert_eq!(Format::from_path("web.txt.gz"), Some(Format::EdgeList));
+  assert_eq!(Format::from_path("web.mtx"), Some(Format::MatrixMarket));
+  assert_eq!(Format::from_path("web"), None);
+}
+
+#[test]
+fn binary_read() {
+  // Edges (1, 2), (1, 3) and (3, 4)
+  let mut content = b"10\np col 4 3\n".to_vec();
+  content.extend_from_slice(&[0x00, 0x80, 0x80, 0x20]);
+  let graph = binary::read(&content[..], Policy::Strict).unwrap().graph;
+  let mut edges = graph.edges(); edges.sort();
+  assert_eq!(edges, vec![(1, 2), (1, 3), (3, 4)]);
+}
+
+#[test]
+fn binary_invalid() {
+  let contents: [&[u8]; 6] = [b"x\np col 2 1\n", b"20\np col 2 1\n",
+    b"10\np col 2 1\n\x00", b"10\np col 2 2\n\x00\x80",
+    b"1999999999990\np col 2 1\n", b"23\np col 99999999999 0\n"];
+  for content in contents.iter() {
+    match binary::read(*content, Policy::Strict) {
+      Err(Error::Parse { .. }) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+  // Self-loops are skipped under the lenient policy
+  let content = b"10\np col 2 2\n\x00\xc0";
+  let instance = binary::read(&content[..], Policy::Lenient).unwrap();
+  let (graph, diagnostics) = (instance.graph, instance.diagnostics);
+  assert_eq!(graph.elen(), 1);
+  assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn binary_write_and_read() {
+  let content = fs::read_to_string("db/C125.9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let mut bytes = vec![];
+  binary::write(&mut bytes, &graph).unwrap();
+  let read = binary::read(&bytes[..], Policy::Strict).unwrap().graph;
+  let mut edges = graph.edges(); edges.sort();
+  let mut read_edges = read.edges(); read_edges.sort();
+  assert_eq!(edges, read_edges);
+}
+
+#[test]
+fn format_detect() {
+  let content = fs::read("db/I1.clq").unwrap();
+  assert_eq!(Format::detect(&content), Format::Dimacs);
+  let mut bytes = vec![];
+  let graph = dimacs::parse("p col 2 1\ne 1 2\n", Policy::Strict).unwrap()
+    .graph;
+  binary::write(&mut bytes, &graph).unwrap();
+  assert_eq!(Format::detect(&bytes), Format::DimacsBinary);
+  assert_eq!(Format::detect(b"%%MatrixMarket matrix coordinate pattern"),
+    Format::MatrixMarket);
+  assert_eq!(Format::detect(b"# Nodes: 2\n10 20\n"), Format::EdgeList);
+  assert_eq!(Format::detect(b"10 20\n"), Format::EdgeList);
+  let graph = io::read_from(&bytes[..], None, Policy::Strict, None).unwrap()
+    .graph;
+  assert!(graph.contains_edge((1, 2)));
+}
+
+#[test]
+fn edgelist_read() {
+  let content = "# SNAP comment\n10\t20\n20 abc 1.5\n\n% comment\n30 10\n";
+  let instance = edgelist::read(content.as_bytes(), Policy::Strict).unwrap();
+  let graph = instance.graph;
+  assert_eq!(graph.nlen(), 4);
+  let mut edges = graph.edges(); edges.sort();
+  assert_eq!(edges, vec![(1, 2), (1, 4), (2, 3)]);
+  let ids = vec!["10", "20", "abc", "30"];
+  assert_eq!(instance.ids.unwrap(), ids);
+}
+
+#[test]
+fn edgelist_invalid() {
+  for content in &["1 2\n1 2\n", "1 2\n2 1\n2 1\n", "1 1\n", "1 2\n3\n"] {
+    match edgelist::read(content.as_bytes(), Policy::Strict) {
+      Err(Error::Parse { .. }) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+  match edgelist::read(&b"# empty\n"[..], Policy::Strict) {
+    Err(Error::EmptyGraph) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+  let instance = edgelist::read(&b"1 2\n2 1\n1 2\n1 1\n"[..],
+    Policy::Lenient).unwrap();
+  assert_eq!(instance.graph.elen(), 1);
+  assert_eq!(instance.diagnostics.len(), 2);
+}
+
+#[test]
+fn edgelist_both_directions() {
+  // The edges listed in both directions are merged silently
+  let content = "1 2\n2 1\n2 3\n3 2\n1 3\n";
+  let instance = edgelist::read(content.as_bytes(), Policy::Strict).unwrap();
+  assert_eq!(instance.graph.elen(), 3);
+  assert!(instance.diagnostics.is_empty());
+}
+
+#[test]
+fn mtx_read() {
+  let content = "%%MatrixMarket matrix coordinate pattern symmetric\n\
+    % comment\n4 4 3\n2 1\n3 1\n4 3\n";
+  let graph = mtx::read(content.as_bytes(), Policy::Strict).unwrap().graph;
+  let mut edges = graph.edges(); edges.sort();
+  assert_eq!(edges, vec![(1, 2), (1, 3), (3, 4)]);
+  // General matrices store both triangles
+  let content = "%%MatrixMarket matrix coordinate real general\n\
+    3 3 4\n1 2 1.0\n2 1 1.0\n2 3 0.5\n3 2 0.5\n";
+  let graph = mtx::read(content.as_bytes(), Policy::Strict).unwrap().graph;
+  assert_eq!(graph.elen(), 2);
+}
+
+#[test]
+fn mtx_invalid() {
+  let contents = ["%%MatrixMarket matrix array real general\n2 2\n",
+    "%%MatrixMarket matrix coordinate pattern symmetric\n2 3 1\n1 2\n",
+    "%%MatrixMarket matrix coordinate pattern symmetric\n2 2 1\n1 3\n",
+    "%%MatrixMarket matrix coordinate pattern symmetric\n2 2 2\n1 2\n",
+    "%%MatrixMarket matrix coordinate pattern symmetric\n2 2 1\n1 1\n",
+    "%%MatrixMarket matrix coordinate pattern symmetric\n% comment\n",
+    "2 2 1\n1 2\n"];
+  for content in &contents {
+    match mtx::read(content.as_bytes(), Policy::Strict) {
+      Err(Error::Parse { .. }) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+}
+
+#[test]
+fn metis_read() {
+  let content = "% comment\n4 3 011\n5 2 1 3 2\n1 1 1\n2 1 2 4 1\n7 3 1\n";
+  let instance = metis::read(content.as_bytes(), Policy::Strict).unwrap();
+  let mut edges = instance.graph.edges(); edges.sort();
+  assert_eq!(edges, vec![(1, 2), (1, 3), (3, 4)]);
+  assert_eq!(instance.weights.unwrap(), vec![5.0, 1.0, 2.0, 7.0]);
+  // Nodes without adjacent nodes have empty lines
+  let content = "3 1\n2\n1\n\n";
+  let graph = metis::read(content.as_bytes(), Policy::Strict).unwrap().graph;
+  assert_eq!(graph.nlen(), 3);
+  assert_eq!(graph.degree_of(3), 0);
+}
+
+#[test]
+fn metis_invalid() {
+  let contents = ["2 1\n2\n", "2 1\n2\n1\n1\n", "2 1\n2\n\n",
+    "2 1\n2 2\n1\n", "2 1\n1\n1\n", "2 1\n3\n1\n", "2 1 2\n2\n1\n",
+    "2 1 001\n2\n1 1\n", "2 2\n2\n1\n", "1 0 010 0\n\n", "1 0 010 0\n1\n",
+    "1 0 110 18446744073709551615\n1\n"];
+  for content in &contents {
+    match metis::read(content.as_bytes(), Policy::Strict) {
+      Err(Error::Parse { .. }) => (),
+      r => panic!("unexpected result: {:?}: {:?}", content, r),
+    }
+  }
+}
+
+#[test]
+fn metis_write_and_read() {
+  let content = fs::read_to_string("db/I9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let weights: Vec<f64> = (1..=graph.nlen()).map(|n| n as f64).collect();
+  let mut bytes = vec![];
+  metis::write(&mut bytes, &graph, Some(&weights)).unwrap();
+  let instance = metis::read(&bytes[..], Policy::Strict).unwrap();
+  let mut edges = graph.edges(); edges.sort();
+  let mut read_edges = instance.graph.edges(); read_edges.sort();
+  assert_eq!(edges, read_edges);
+  assert_eq!(instance.weights.unwrap(), weights);
+  match metis::write(vec![], &graph, Some(&[0.5])) {
+    Err(Error::InvalidParameter(_)) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn adjlist_read() {
+  let content = "# comment\na b c\nb a\nc\nd\n";
+  let instance = adjlist::read(content.as_bytes(), Policy::Strict).unwrap();
+  let mut edges = instance.graph.edges(); edges.sort();
+  assert_eq!(edges, vec![(1, 2), (1, 3)]);
+  assert_eq!(instance.graph.nlen(), 4);
+  assert_eq!(instance.ids.unwrap(), vec!["a", "b", "c", "d"]);
+  for content in &["a a\n", "a b b\n", "a b\nb a\na b\n"] {
+    match adjlist::read(content.as_bytes(), Policy::Strict) {
+      Err(Error::Parse { .. }) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+}
+
+#[test]
+fn adjlist_write_renumbers() {
+  let mut graph = Graph::new(5);
+  graph.insert_edge((2, 4));
+  graph.insert_edge((4, 5));
+  graph.remove_node(1);
+  graph.remove_node(3);
+  let mut bytes = vec![];
+  adjlist::write(&mut bytes, &graph).unwrap();
+  assert_eq!(String::from_utf8(bytes).unwrap(), "1 2\n2 3\n3\n");
+}
+
+#[test]
+fn adjlist_write_and_read() {
+  let content = fs::read_to_string("db/I9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let mut bytes = vec![];
+  adjlist::write(&mut bytes, &graph).unwrap();
+  let instance = adjlist::read(&bytes[..], Policy::Strict).unwrap();
+  let ids = instance.ids.unwrap();
+  let mut edges: Vec<(String, String)> = instance.graph.edges().iter()
+    .map(|&(a, b)| (ids[a - 1].clone(), ids[b - 1].clone())).collect();
+  let mut expected: Vec<(String, String)> = graph.edges().iter()
+    .map(|&(a, b)| (a.to_string(), b.to_string())).collect();
+  for e in edges.iter_mut().chain(expected.iter_mut()) {
+    if e.0.parse::<usize>().unwrap() > e.1.parse::<usize>().unwrap() {
+      *e = (e.1.clone(), e.0.clone());
+    }
+  }
+  edges.sort(); expected.sort();
+  assert_eq!(edges, expected);
+}
+
+/// GraphML graph with a weight key and a triangle.
+const GRAPHML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="weight" attr.type="double">
+    <default>1.5</default>
+  </key>
+  <key id="d1" for="node" attr.name="label" attr.type="string"/>
+  <graph id="G" edgedefault="undirected">
+    <node id="alice"><data key="d0">2.0</data><data key="d1">A</data></node>
+    <node id="bob"><data key="d0"> 3 </data></node>
+    <node id="carol"/>
+    <node id="dave"/>
+    <edge source="alice" target="bob"/>
+    <edge source="bob" target="carol"/>
+    <edge source="carol" target="alice"/>
+    <edge source="carol" target="dave"/>
+  </graph>
+</graphml>
+"#;
+
+/// GEXF graph with a weight attribute and a triangle.
+const GEXF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gexf xmlns="http://gexf.net/1.3" version="1.3">
+  <graph defaultedgetype="undirected">
+    <attributes class="node">
+      <attribute id="0" title="weight" type="float">
+        <default>1.5</default>
+      </attribute>
+    </attributes>
+    <nodes>
+      <node id="alice" label="Alice">
+        <attvalues><attvalue for="0" value="2.0"/></attvalues>
+      </node>
+      <node id="bob"><attvalues><attvalue for="0" value="3"/></attvalues></node>
+      <node id="carol"/>
+      <node id="dave"/>
+    </nodes>
+    <edges>
+      <edge id="0" source="alice" target="bob"/>
+      <edge id="1" source="bob" target="carol"/>
+      <edge id="2" source="carol" target="alice"/>
+      <edge id="3" source="carol" target="dave"/>
+    </edges>
+  </graph>
+</gexf>
+"#;
+
+#[test]
+fn xml_read() {
+  let instances = [
+    graphml::read(GRAPHML.as_bytes(), Policy::Strict, Some("weight")),
+    gexf::read(GEXF.as_bytes(), Policy::Strict, Some("weight")),
+  ];
+  for instance in instances.iter() {
+    let instance = instance.as_ref().unwrap();
+    let mut edges = instance.graph.edges(); edges.sort();
+    assert_eq!(edges, vec![(1, 2), (1, 3), (2, 3), (3, 4)]);
+    let ids: Vec<String> = (1..=4).map(|n| instance.id(n)).collect();
+    assert_eq!(ids, vec!["alice", "bob", "carol", "dave"]);
+    assert_eq!(instance.weights.as_ref().unwrap(), &vec![2.0, 3.0, 1.5, 1.5]);
+  }
+  let graph = graphml::read(GRAPHML.as_bytes(), Policy::Strict, None).unwrap();
+  assert!(graph.weights.is_none());
+  assert_eq!(Format::detect(GRAPHML.as_bytes()), Format::GraphMl);
+  assert_eq!(Format::detect(GEXF.as_bytes()), Format::Gexf);
+}
+
+#[test]
+fn xml_invalid() {
+  let graphml = GRAPHML.replace("alice\"/>", "alice\"/><edge source=\"bob\"\
+    target=\"alice\"/>");
+  let gexf = GEXF.replace("<edge id=\"0\"", "<edge source=\"a\"/><edge");
+  let results = [
+    graphml::read(GRAPHML.as_bytes(), Policy::Strict, Some("size")),
+    graphml::read(graphml.as_bytes(), Policy::Strict, None),
+    graphml::read(&b"<graphml><graph>"[..], Policy::Strict, None),
+    graphml::read(&b"<graphml><node></graphml>"[..], Policy::Strict, None),
+    gexf::read(GEXF.as_bytes(), Policy::Strict, Some("size")),
+    gexf::read(gexf.as_bytes(), Policy::Strict, None),
+  ];
+  for r in results.iter() {
+    match r {
+      Err(Error::Parse { .. }) | Err(Error::EmptyGraph) => (),
+      r => panic!("unexpected result: {:?}", r),
+    }
+  }
+}
+
+#[test]
+fn xml_lenient() {
+  let graphml = "<graphml><graph>\n<node id=\"a\"/><node id=\"b\"/>\n\
+    <edge source=\"a\" target=\"b\"/>\n<edge source=\"b\" target=\"a\"/>\n\n\
+    <edge source=\"a\" target=\"a\"/>\n</graph></graphml>\n";
+  let gexf = "<gexf><graph><nodes>\n<node id=\"a\"/><node id=\"b\"/>\n\
+    </nodes><edges><edge source=\"a\" target=\"b\"/>\n\
+    <edge source=\"b\" target=\"a\"/>\n\n<edge source=\"a\" target=\"a\"/>\n\
+    </edges></graph></gexf>\n";
+  let instances = [
+    graphml::read(graphml.as_bytes(), Policy::Lenient, None).unwrap(),
+    gexf::read(gexf.as_bytes(), Policy::Lenient, None).unwrap(),
+  ];
+  for instance in instances.iter() {
+    assert_eq!(instance.graph.elen(), 1);
+    let lines: Vec<usize> = instance.diagnostics.iter().map(|d| d.line)
+      .collect();
+    assert_eq!(lines, vec![4, 6]);
+  }
+  match graphml::read(graphml.as_bytes(), Policy::Strict, None) {
+    Err(Error::Parse { line: 4, column: 30, .. }) => (),
+    r => panic!("unexpected result: {:?}", r),
+  }
+}
+
+#[test]
+fn dot_write() {
+  let graph = dimacs::parse("p col 4 4\ne 1 2\ne 1 3\ne 2 3\ne 3 4\n",
+    Policy::Strict).unwrap().graph;
+  let mut clique = Graph::new(3);
+  clique.insert_edge((1, 2));
+  clique.insert_edge((1, 3));
+  clique.insert_edge((2, 3));
+  let ids: Vec<String> = vec!["a".into(), "b\"".into(), "c".into(),
+    "d".into()];
+  let mut bytes = vec![];
+  dot::write(&mut bytes, &graph, &clique, Some(&ids)).unwrap();
+  let content = String::from_utf8(bytes).unwrap();
+  assert!(content.starts_with("graph G {"));
+  assert!(content.contains("\"a\" [style=filled"));
+  assert!(content.contains("\"d\";"));
+  assert!(content.contains("\"a\" -- \"b\\\"\" [color=\"#d62728\""));
+  assert!(content.contains("\"c\" -- \"d\" [color=gray];"));
+  let mut bytes = vec![];
+  dot::write_clique(&mut bytes, &clique, None).unwrap();
+  let content = String::from_utf8(bytes).unwrap();
+  assert_eq!(content.matches(" -- ").count(), 3);
+  assert!(!content.contains("\"4\""));
+}
+
+#[test]
+fn write_to_and_read() {
+  let content = fs::read_to_string("db/I9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let mut edges = graph.edges(); edges.sort();
+  let weights: Vec<f64> = (1..=graph.nlen()).map(|n| n as f64).collect();
+  for &format in &[Format::Dimacs, Format::DimacsBinary, Format::MatrixMarket,
+    Format::Metis, Format::GraphMl, Format::Gexf] {
+      let mut bytes = vec![];
+      io::write_to(&mut bytes, format, &graph, Some(&weights)).unwrap();
+      let instance = io::read_from(&bytes[..], Some(format), Policy::Strict,
+        Some("weight")).unwrap();
+      let mut read_edges = instance.graph.edges(); read_edges.sort();
+      assert_eq!(edges, read_edges, "{:?}", format);
+      if [Format::Metis, Format::GraphMl, Format::Gexf].contains(&format) {
+        assert_eq!(instance.weights.unwrap(), weights, "{:?}", format);
+      }
+  }
+  // Edge lists keep only the nodes with edges, which are numbered in order
+  let mut bytes = vec![];
+  edgelist::write(&mut bytes, &graph).unwrap();
+  let instance = edgelist::read(&bytes[..], Policy::Strict).unwrap();
+  assert_eq!(instance.graph.elen(), graph.elen());
+}
+
+#[test]
+fn normalized_edges() {
+  let mut graph = Graph::default();
+  for &n in &[10, 4, 7] { graph.insert_node(n); }
+  graph.insert_edge((10, 4));
+  graph.insert_edge((7, 4));
+  graph.insert_edge((10, 7));
+  assert_eq!(io::normalized_edges(&graph), vec![(1, 2), (1, 3), (2, 3)]);
+  let mut bytes = vec![];
+  dimacs::write(&mut bytes, &graph).unwrap();
+  assert_eq!(String::from_utf8(bytes).unwrap(),
+    "p col 3 3\ne 1 2\ne 1 3\ne 2 3\n");
+}
+
+#[test]
+fn write_file_and_read_file() {
+  let dir = std::env::temp_dir();
+  let content = "% comment\na b\nb a\na b\nb b\nc a\n";
+  let input = dir.join("max-clique-convert-test.txt");
+  fs::write(&input, content).unwrap();
+  let instance = io::read_file(input.to_str().unwrap(), None, Policy::Lenient,
+    None, false).unwrap();
+  assert_eq!(instance.diagnostics.len(), 2);
+  let output = dir.join("max-clique-convert-test.clq.gz");
+  let output = output.to_str().unwrap();
+  io::write_file(output, None, &instance.graph, None).unwrap();
+  let mut text = String::new();
+  io::open(output).unwrap().read_to_string(&mut text).unwrap();
+  assert_eq!(text, "p col 3 2\ne 1 2\ne 1 3\n");
+  fs::remove_file(input).unwrap();
+  fs::remove_file(output).unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn write_file_full_disk() {
+  // The errors of the compressed trailers are reported
+  let graph = dimacs::parse("p col 2 1\ne 1 2\n", Policy::Strict).unwrap()
+    .graph;
+  for extension in &["clq", "clq.gz", "clq.bz2", "clq.xz"] {
+    let link = std::env::temp_dir()
+      .join(format!("max-clique-full-test.{}", extension));
+    let _ = fs::remove_file(&link);
+    std::os::unix::fs::symlink("/dev/full", &link).unwrap();
+    let result = io::write_file(link.to_str().unwrap(), None, &graph, None);
+    fs::remove_file(&link).unwrap();
+    match result {
+      Err(Error::Io(_)) => (),
+      r => panic!("unexpected result: {}: {:?}", extension, r),
+    }
+  }
+}
+
+#[test]
+fn report_write() {
+  let mut graph = Graph::new(3);
+  graph.insert_edge((1, 2));
+  let solution = solver::solve_with(&graph, &Solver::BranchAndBound, None)
+    .unwrap();
+  let ids = vec!["a,b".to_string(), "c".to_string(), "d".to_string()];
+  let report = Report::new("g.txt", &graph, "BranchAndBound", &solution,
+    Some(&ids), Some(&[1.0, 2.0, 3.0]));
+  assert_eq!(report.clique, vec!["a,b", "c"]);
+  assert_eq!(report.size, 2);
+  assert_eq!(report.weight, Some(3.0));
+  assert!((report.density - 1.0 / 3.0).abs() < 1e-12);
+  assert!(report.optimal);
+  let reports = vec![report];
+  // Check the CSV output
+  let mut bytes = vec![];
+  io::report::write(&mut bytes, ReportFormat::Csv, &reports).unwrap();
+  let text = String::from_utf8(bytes).unwrap();
+  let lines: Vec<&str> = text.lines().collect();
+  assert_eq!(lines.len(), 2);
+  assert!(lines[0].starts_with("instance,nodes,edges,density,solver,clique"));
+  assert!(lines[1].starts_with("g.txt,3,1,"));
+  assert!(lines[1].contains(",BranchAndBound,\"a,b c\",2,3,"));
+  // Check the JSON output
+  let mut bytes = vec![];
+  io::report::write(&mut bytes, ReportFormat::Json, &reports).unwrap();
+  let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+  assert_eq!(json[0]["instance"], "g.txt");
+  assert_eq!(json[0]["clique"][0], "a,b");
+  assert_eq!(json[0]["size"], 2);
+  assert_eq!(json[0]["optimal"], true);
+}
+
+#[test]
+fn report_format() {
+  assert_eq!(ReportFormat::from_path("out.csv"), ReportFormat::Csv);
+  assert_eq!(ReportFormat::from_path("out.json"), ReportFormat::Json);
+  assert_eq!(ReportFormat::from_path("-"), ReportFormat::Json);
+  assert_eq!("csv".parse(), Ok(ReportFormat::Csv));
+  assert!("xml".parse::<ReportFormat>().is_err());
+  assert_eq!(io::instance_name("db/I9.clq"), "I9.clq");
+  assert_eq!(io::instance_name("-"), "stdin");
+}
+
+#[test]
+fn result_path() {
+  let path = io::result_path("db/I9.clq", None, Some(Path::new("out/a")));
+  assert_eq!(path, Path::new("out/a/result_I9.clq"));
+  let path = io::result_path("-", None, None);
+  assert_eq!(path, io::solutions_dir().join("result_stdin"));
+  let path = io::result_path("db/I9.clq", Some(Path::new("r.txt")), None);
+  assert_eq!(path, Path::new("r.txt"));
+}
+
+#[test]
+fn write_overwrite() {
+  let dir = std::env::temp_dir().join("max-clique-overwrite-test");
+  let _ = fs::remove_dir_all(&dir);
+  let path = dir.join("nested").join("result_g");
+  let mut result = Graph::new(3);
+  result.insert_edge((1, 3));
+  result.remove_node(2);
+  // The missing directories are created
+  let written = io::write(&path, &result, None, Overwrite::Fail).unwrap();
+  assert_eq!(written, path);
+  assert_eq!(fs::read_to_string(&path).unwrap(), "1 3\n");
+  // An existing file is kept, renamed or replaced
+  assert!(io::write(&path, &result, None, Overwrite::Fail).is_err());
+  let ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+  let written = io::write(&path, &result, Some(&ids), Overwrite::Rename)
+    .unwrap();
+  assert_eq!(written, dir.join("nested").join("result_g.1"));
+  assert_eq!(fs::read_to_string(&written).unwrap(), "a c\n");
+  io::write(&path, &result, Some(&ids), Overwrite::Replace).unwrap();
+  assert_eq!(fs::read_to_string(&path).unwrap(), "a c\n");
+  assert_eq!("rename".parse(), Ok(Overwrite::Rename));
+  fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn solution_read() {
+  let content = fs::read_to_string("db/I0.clq").unwrap();
+  let instance = dimacs::parse(&content, Policy::Strict).unwrap();
+  let expected = fs::read_to_string("db/expected/R_I0.txt").unwrap();
+  let solution = solution::read(expected.as_bytes(), &instance).unwrap();
+  assert_eq!(solution.cliques, vec![vec![1, 2], vec![2, 3]]);
+  assert_eq!(solution.size(), Some(2));
+  let solution = solution::read("# comment\n\n3 1 \n".as_bytes(), &instance)
+    .unwrap();
+  assert_eq!(solution.cliques, vec![vec![3, 1]]);
+  for content in &["1 9\n", "1 x\n", "1 2 1\n"] {
+    assert!(matches!(solution::read(content.as_bytes(), &instance),
+      Err(Error::Parse { line: 1, .. })));
+  }
+  // Check the original ids of the instance
+  let content = "a b\nb c\n";
+  let instance = edgelist::read(content.as_bytes(), Policy::Strict).unwrap();
+  let solution = solution::read("c b\n".as_bytes(), &instance).unwrap();
+  assert_eq!(solution.cliques, vec![vec![3, 2]]);
+  assert!(solution::read("1 2\n".as_bytes(), &instance).is_err());
+}
+
+#[test]
+fn solution_read_notes() {
+  let content = fs::read_to_string("db/I6.clq").unwrap();
+  let instance = dimacs::parse(&content, Policy::Strict).unwrap();
+  let solution = solution::read("1 2\n(Any 2-clique)\n".as_bytes(), &instance)
+    .unwrap();
+  assert_eq!(solution.cliques, vec![vec![1, 2]]);
+  assert_eq!(solution.noted_size, Some(2));
+  let solution = solution::read("Any 3-clique (a note)\n".as_bytes(),
+    &instance).unwrap();
+  assert!(solution.cliques.is_empty());
+  assert_eq!(solution.size(), Some(3));
+}
+
+#[test]
+fn expected_path() {
+  assert_eq!(io::expected_path("db/C125.9.clq"),
+    Path::new("db/expected/R_C125.9.txt"));
+  assert!(io::expected_path("db/I0.clq").is_file());
+}
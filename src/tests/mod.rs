@@ -1,2 +1,18 @@
+use crate::graph::Graph;
+
+mod bench;
+mod bounds;
+mod color;
+mod generate;
+#[allow(clippy::bool_assert_comparison)]
 mod graph;
+mod io;
 mod solver;
+mod verify;
+
+/// Returns a cycle of the given number of nodes.
+fn cycle(nodes: usize) -> Graph {
+  let mut graph = Graph::new(nodes);
+  for a in 1..=nodes { graph.insert_edge((a, a % nodes + 1)); }
+  graph
+}
@@ -1,5 +1,7 @@
+use std::fs;
+use std::time::Duration;
 use crate::graph::*;
-use crate::io::Solver;
+use crate::io::{dimacs, Policy, Solver};
 use crate::solver;
 
 #[test]
@@ -68,3 +70,118 @@ fn backtracking_3_clique_ii() {
   assert_eq!(clique_nodes, result_nodes);
   assert_eq!(clique_edges, result_edges);
 }
+
+#[test]
+fn solve_edgeless_graph() {
+  // The branch and bound keeps a single node
+  let graph = Graph::new(3);
+  for solver in &Solver::ALL {
+    let result = solver::solve(&graph, solver).unwrap();
+    assert_eq!(result.nlen(), 1, "{:?}", solver);
+  }
+}
+
+#[test]
+fn solve_two_edges_isolated_nodes() {
+  // The pair of adjacent nodes is not taken from an isolated node
+  let mut graph = Graph::new(100);
+  graph.insert_edge((2, 3));
+  graph.insert_edge((3, 4));
+  for solver in &Solver::ALL {
+    let result = solver::solve(&graph, solver).unwrap();
+    let mut nodes = result.nodes(); nodes.sort();
+    assert!(nodes == vec![2, 3] || nodes == vec![3, 4], "{:?}", solver);
+    assert!(result.is_complete());
+  }
+}
+
+#[test]
+fn weighted_clique() {
+  let mut graph = Graph::new(5);
+  graph.insert_edge((1, 2));
+  graph.insert_edge((1, 3));
+  graph.insert_edge((2, 3));
+  graph.insert_edge((3, 4));
+  graph.insert_edge((4, 5));
+  let weights = [1.0, 1.0, 2.0, 5.0, 1.0];
+  let result = solver::solve_weighted(&graph, &weights).unwrap();
+  let mut result_nodes = result.nodes(); result_nodes.sort();
+  assert_eq!(result_nodes, vec![3, 4]);
+  assert!(result.is_complete());
+  let weights = [3.0, 3.0, 3.0, 5.0, 1.0];
+  let result = solver::solve_weighted(&graph, &weights).unwrap();
+  let mut result_nodes = result.nodes(); result_nodes.sort();
+  assert_eq!(result_nodes, vec![1, 2, 3]);
+}
+
+#[test]
+fn weighted_invalid() {
+  let graph = Graph::new(3);
+  assert!(solver::solve_weighted(&graph, &[1.0, 1.0]).is_err());
+  assert!(solver::solve_weighted(&Graph::default(), &[]).is_err());
+}
+
+#[test]
+fn solve_with_statistics() {
+  let content = fs::read_to_string("db/I9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let solution = solver::solve_with(&graph, &Solver::BranchAndBound, None)
+    .unwrap();
+  assert!(solution.optimal);
+  assert!(solution.nodes > 0);
+  assert_eq!(solution.clique.nlen(), 4);
+  assert!(solution.clique.is_complete());
+}
+
+#[test]
+fn solve_with_time_limit() {
+  let content = fs::read_to_string("db/C125.9.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let limit = Duration::from_millis(50);
+  for solver in &[Solver::Backtracking, Solver::BranchAndBound] {
+    let solution = solver::solve_with(&graph, solver, Some(limit)).unwrap();
+    assert!(!solution.optimal);
+    assert!(solution.runtime < Duration::from_secs(5));
+    assert!(solution.clique.is_empty() || solution.clique.is_complete());
+  }
+  let weights = vec![1.0; graph.nlen()];
+  let solution = solver::solve_weighted_with(&graph, &weights, Some(limit))
+    .unwrap();
+  assert!(!solution.optimal);
+}
+
+#[test]
+fn preprocess_peels_graph() {
+  // A 4-clique with a path hanging from it
+  let mut graph = Graph::new(7);
+  for &e in &[(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4), (4, 5), (5, 6),
+    (6, 7)] {
+      graph.insert_edge(e);
+  }
+  let reduction = solver::preprocess(&graph).unwrap();
+  let mut incumbent = reduction.incumbent.clone(); incumbent.sort();
+  assert_eq!(incumbent, vec![1, 2, 3, 4]);
+  // No node belongs to a clique larger than the incumbent
+  assert!(reduction.graph.is_empty());
+  assert_eq!(reduction.removed_nodes, 7);
+  assert_eq!(reduction.removed_edges, 9);
+  let solution = solver::solve_reduced(&graph, &reduction,
+    &Solver::Backtracking, None).unwrap();
+  assert!(solution.optimal);
+  assert_eq!(solution.clique.nlen(), 4);
+  assert!(solver::preprocess(&Graph::default()).is_err());
+}
+
+#[test]
+fn solve_reduced() {
+  let content = fs::read_to_string("db/I22.clq").unwrap();
+  let graph = dimacs::parse(&content, Policy::Strict).unwrap().graph;
+  let reduction = solver::preprocess(&graph).unwrap();
+  for solver in &[Solver::Backtracking, Solver::BranchAndBound] {
+    let solution = solver::solve_reduced(&graph, &reduction, solver, None)
+      .unwrap();
+    assert!(solution.optimal);
+    assert_eq!(solution.clique.nlen(), 20);
+    assert!(solution.clique.is_complete());
+  }
+}
@@ -0,0 +1,49 @@
+use crate::Error;
+use crate::graph::Graph;
+use crate::verify;
+
+/// Returns a triangle `1 2 3` with a pendant node 4 adjacent to 3.
+fn graph() -> Graph {
+  let mut graph = Graph::new(4);
+  graph.insert_edge((1, 2));
+  graph.insert_edge((1, 3));
+  graph.insert_edge((2, 3));
+  graph.insert_edge((3, 4));
+  graph
+}
+
+#[test]
+fn verify_maximal_clique() {
+  let result = verify::verify(&graph(), &[3, 1, 2]).unwrap();
+  assert_eq!(result.size, 3);
+  assert!(result.is_clique());
+  assert!(result.is_maximal());
+  let result = verify::verify(&graph(), &[3, 4]).unwrap();
+  assert!(result.is_maximal());
+}
+
+#[test]
+fn verify_not_maximal() {
+  let result = verify::verify(&graph(), &[1, 3]).unwrap();
+  assert!(result.is_clique());
+  assert_eq!(result.extension, Some(2));
+  assert!(!result.is_maximal());
+  let result = verify::verify(&graph(), &[]).unwrap();
+  assert_eq!(result.extension, Some(1));
+}
+
+#[test]
+fn verify_not_clique() {
+  let result = verify::verify(&graph(), &[1, 2, 4]).unwrap();
+  assert_eq!(result.missing_edge, Some((1, 4)));
+  assert!(!result.is_clique());
+  assert!(!result.is_maximal());
+}
+
+#[test]
+fn verify_invalid_nodes() {
+  assert!(matches!(verify::verify(&graph(), &[1, 5]),
+    Err(Error::InvalidNode(5))));
+  assert!(matches!(verify::verify(&graph(), &[1, 2, 1]),
+    Err(Error::DuplicateNode(1))));
+}
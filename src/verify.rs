@@ -0,0 +1,59 @@
+use crate::error::Error;
+use crate::graph::Graph;
+
+/// Outcome of checking a claimed clique against a graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verification {
+  /// Number of nodes of the claimed clique.
+  pub size: usize,
+  /// A pair of claimed nodes that are not adjacent, if there is one.
+  pub missing_edge: Option<(usize, usize)>,
+  /// A node outside the claimed clique that is adjacent to all of its nodes,
+  /// if there is one.
+  pub extension: Option<usize>,
+}
+
+impl Verification {
+  /// Returns true if the claimed nodes form a clique or false otherwise.
+  pub fn is_clique(&self) -> bool {
+    self.missing_edge.is_none()
+  }
+
+  /// Returns true if the claimed nodes form a clique that cannot be extended
+  /// by any other node or false otherwise.
+  pub fn is_maximal(&self) -> bool {
+    self.is_clique() && self.extension.is_none()
+  }
+}
+
+/// Checks whether some distinct nodes of a graph form a clique and whether
+/// it is maximal.
+pub fn verify(graph: &Graph, nodes: &[usize]) -> Result<Verification, Error> {
+  // Check if the nodes belong to the graph and are distinct
+  for (i, &n) in nodes.iter().enumerate() {
+    if !graph.contains_node(n) { return Err(Error::InvalidNode(n)) }
+    if nodes[..i].contains(&n) { return Err(Error::DuplicateNode(n)) }
+  }
+  // Look for a pair of nodes that are not adjacent
+  let mut missing_edge = None;
+  'pairs: for (i, &a) in nodes.iter().enumerate() {
+    for &b in &nodes[i + 1..] {
+      if !graph.contains_edge((a, b)) {
+        missing_edge = Some((a, b));
+        break 'pairs;
+      }
+    }
+  }
+  // Look for a node adjacent to all nodes
+  let mut extension = None;
+  if missing_edge.is_none() {
+    let mut candidates = match nodes.first() {
+      Some(&n) => graph.adjlst_of(n).clone(),
+      None => graph.nodes(),
+    };
+    candidates.sort();
+    extension = candidates.into_iter().find(|&c| !nodes.contains(&c) &&
+      nodes.iter().all(|&n| graph.contains_edge((c, n))));
+  }
+  Ok(Verification { size: nodes.len(), missing_edge, extension })
+}
@@ -0,0 +1,95 @@
+//! Regression tests of the solvers against the expected results of `db/`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use max_clique::io::{self, Instance, Policy, SolutionFile, Solver};
+use max_clique::{solver, verify};
+
+/// Time limit of each solver run.
+const TIME_LIMIT: Duration = Duration::from_secs(30);
+
+/// Instances on which each solver hits the time limit.
+const SKIP: &[(Solver, &[&str])] = &[
+  (Solver::Backtracking, &["C125.9", "C250.9", "I11", "I12", "I13", "I14",
+    "I15", "I16", "I17", "I18", "I19", "I20", "I21", "I22", "I23", "I24",
+    "I25"]),
+  (Solver::BranchAndBound, &["C125.9", "C250.9", "I23"]),
+];
+
+/// Returns the paths of the instances of `db/` sorted by name.
+fn instances() -> Vec<PathBuf> {
+  let mut paths: Vec<PathBuf> = fs::read_dir("db").unwrap()
+    .map(|entry| entry.unwrap().path())
+    .filter(|path| path.extension().is_some_and(|e| e == "clq"))
+    .collect();
+  paths.sort();
+  paths
+}
+
+/// Returns the name of an instance path without its extension.
+fn name(path: &Path) -> String {
+  path.file_stem().unwrap().to_string_lossy().into_owned()
+}
+
+/// Reads an instance and its expected maximum cliques.
+fn read(path: &Path) -> (Instance, SolutionFile) {
+  let instance = io::read_file(path.to_str().unwrap(), None, Policy::Strict,
+    None, false).unwrap();
+  let expected = io::expected_path(path);
+  let expected = io::read_solution(expected.to_str().unwrap(), &instance)
+    .unwrap();
+  assert!(expected.size().is_some(), "{}: no expected size", name(path));
+  (instance, expected)
+}
+
+/// Runs a solver over every instance not skipped for it and checks that the
+/// search finishes with a clique of the expected size.
+fn check(solver: Solver) {
+  let skip = SKIP.iter().find(|(s, _)| *s == solver).map_or(&[][..], |s| s.1);
+  let paths = instances();
+  // Check if the skipped instances exist
+  for name in skip {
+    assert!(paths.iter().any(|p| self::name(p) == *name),
+      "{:?}: unknown skipped instance {}", solver, name);
+  }
+  let mut failures = vec![];
+  for path in paths.iter().filter(|p| !skip.contains(&name(p).as_str())) {
+    let (instance, expected) = read(path);
+    let solution = solver::solve_with(&instance.graph, &solver,
+      Some(TIME_LIMIT)).unwrap();
+    let result = verify::verify(&instance.graph, &solution.clique.nodes())
+      .unwrap();
+    if !solution.optimal {
+      failures.push(format!("{}: the time limit was reached", name(path)));
+    } else if !result.is_clique() {
+      failures.push(format!("{}: the result is not a clique", name(path)));
+    } else if Some(result.size) != expected.size() {
+      failures.push(format!("{}: expected {:?} nodes but found {}",
+        name(path), expected.size(), result.size));
+    }
+  }
+  assert!(failures.is_empty(), "{:?}:\n{}", solver, failures.join("\n"));
+}
+
+#[test]
+fn expected_results_are_maximum_cliques() {
+  for path in instances() {
+    let (instance, expected) = read(&path);
+    for clique in &expected.cliques {
+      let result = verify::verify(&instance.graph, clique).unwrap();
+      assert!(result.is_maximal(), "{}: {:?}", name(&path), clique);
+      assert_eq!(Some(result.size), expected.size(), "{}", name(&path));
+    }
+  }
+}
+
+#[test]
+fn backtracking_expected_results() {
+  check(Solver::Backtracking);
+}
+
+#[test]
+fn branch_and_bound_expected_results() {
+  check(Solver::BranchAndBound);
+}
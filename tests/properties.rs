@@ -0,0 +1,243 @@
+//! Property-based tests of the graph invariants and of the solvers against a
+//! brute-force reference on small random graphs.
+
+use std::collections::BTreeSet;
+use proptest::prelude::*;
+use max_clique::color::{self, Algorithm, Coloring};
+use max_clique::graph::Graph;
+use max_clique::io::Solver;
+use max_clique::{bounds, solver, verify};
+
+/// Largest number of nodes of the random graphs.
+const MAX_NODES: usize = 12;
+
+/// Operation over a graph.
+#[derive(Clone, Debug)]
+enum Op {
+  InsertEdge(usize, usize),
+  RemoveEdge(usize, usize),
+  RemoveNode(usize),
+}
+
+/// Returns a random operation over the nodes `1..=nodes` and one node out of
+/// the graph.
+fn op(nodes: usize) -> impl Strategy<Value = Op> {
+  let node = 1..=nodes + 1;
+  prop_oneof![
+    4 => (node.clone(), node.clone()).prop_map(|(a, b)| Op::InsertEdge(a, b)),
+    2 => (node.clone(), node.clone()).prop_map(|(a, b)| Op::RemoveEdge(a, b)),
+    1 => node.prop_map(Op::RemoveNode),
+  ]
+}
+
+/// Returns a random graph of at most `MAX_NODES` nodes.
+fn graph() -> impl Strategy<Value = Graph> {
+  (1..=MAX_NODES).prop_flat_map(|nodes| {
+    let pairs = nodes * (nodes - 1) / 2;
+    (Just(nodes), prop::collection::vec(any::<bool>(), pairs))
+  }).prop_map(|(nodes, edges)| {
+    let mut graph = Graph::new(nodes);
+    let pairs = (1..=nodes).flat_map(|a| (a + 1..=nodes).map(move |b| (a, b)));
+    for (e, _) in pairs.zip(edges).filter(|(_, edge)| *edge) {
+      graph.insert_edge(e);
+    }
+    graph
+  })
+}
+
+/// Returns the size of a maximum clique by checking every subset of nodes.
+fn brute_force(graph: &Graph) -> usize {
+  let mut nodes = graph.nodes(); nodes.sort();
+  let mut best = 0;
+  for subset in 0u32..1 << nodes.len() {
+    let clique: Vec<usize> = nodes.iter().enumerate()
+      .filter(|(i, _)| subset & 1 << i != 0).map(|(_, &n)| n).collect();
+    if clique.len() <= best { continue; }
+    let is_clique = clique.iter().enumerate().all(|(i, &a)|
+      clique[i + 1..].iter().all(|&b| graph.contains_edge((a, b))));
+    if is_clique { best = clique.len(); }
+  }
+  best
+}
+
+/// Checks that a graph matches the expected node and edge sets.
+fn check_invariants(graph: &Graph, nodes: &BTreeSet<usize>,
+  edges: &BTreeSet<(usize, usize)>) -> Result<(), TestCaseError> {
+    let mut graph_nodes = graph.nodes(); graph_nodes.sort();
+    prop_assert_eq!(graph_nodes, nodes.iter().copied().collect::<Vec<_>>());
+    prop_assert_eq!(graph.nlen(), nodes.len());
+    prop_assert_eq!(graph.elen(), edges.len());
+    // Check the degrees
+    let degree = nodes.iter().map(|&n| graph.degree_of(n)).max().unwrap_or(0);
+    prop_assert_eq!(graph.degree(), degree);
+    for &n in nodes {
+      let count = edges.iter().filter(|&&(a, b)| a == n || b == n).count();
+      prop_assert_eq!(graph.degree_of(n), count);
+    }
+    // Check the symmetry of the adjacency lists
+    for &a in nodes {
+      for &b in graph.adjlst_of(a) {
+        prop_assert!(graph.adjlst_of(b).contains(&a), "{} {}", a, b);
+        prop_assert!(edges.contains(&(a.min(b), a.max(b))), "{} {}", a, b);
+      }
+    }
+    Ok(())
+}
+
+/// Returns the core number of each node by peeling the nodes of degree less
+/// than `k` for each `k`.
+fn peeled_cores(graph: &Graph) -> Vec<(usize, usize)> {
+  let mut cores = vec![];
+  for n in graph.nodes() {
+    let mut core = 0;
+    for k in 1..graph.nlen() {
+      let mut graph = graph.clone();
+      while let Some(m) = graph.nodes().into_iter()
+        .find(|&m| graph.degree_of(m) < k) {
+          graph.remove_node(m);
+      }
+      if graph.contains_node(n) { core = k; }
+    }
+    cores.push((n, core));
+  }
+  cores.sort();
+  cores
+}
+
+/// Returns the least number of colors of a graph by trying every number of
+/// colors with a backtracking.
+fn brute_force_colors(graph: &Graph) -> usize {
+  fn extend(graph: &Graph, nodes: &[usize], colors: &mut Vec<usize>,
+    k: usize) -> bool {
+      let i = colors.len();
+      if i == nodes.len() { return true }
+      for c in 0..k {
+        let free = (0..i).all(|j| colors[j] != c
+          || !graph.contains_edge((nodes[i], nodes[j])));
+        if !free { continue; }
+        colors.push(c);
+        if extend(graph, nodes, colors, k) { return true }
+        colors.pop();
+      }
+      false
+  }
+  let nodes = graph.nodes();
+  (1..=nodes.len()).find(|&k| extend(graph, &nodes, &mut vec![], k))
+    .unwrap_or(0)
+}
+
+proptest! {
+  #[test]
+  fn graph_invariants_hold((nodes, ops) in (1..=MAX_NODES).prop_flat_map(|n|
+    (Just(n), prop::collection::vec(op(n), 0..60)))) {
+      let mut graph = Graph::new(nodes);
+      let mut node_set: BTreeSet<usize> = (1..=nodes).collect();
+      let mut edge_set = BTreeSet::new();
+      for op in ops {
+        match op {
+          Op::InsertEdge(a, b) => {
+            let e = (a.min(b), a.max(b));
+            let valid = node_set.contains(&a) && node_set.contains(&b);
+            // Self-loops and duplicate edges are skipped by the readers
+            if a == b || edge_set.contains(&e) { continue; }
+            prop_assert_eq!(graph.try_insert_edge((a, b)).is_ok(), valid);
+            if valid { edge_set.insert(e); }
+          },
+          Op::RemoveEdge(a, b) => {
+            let e = (a.min(b), a.max(b));
+            let valid = edge_set.remove(&e);
+            prop_assert_eq!(graph.try_remove_edge((a, b)).is_ok(), valid);
+          },
+          Op::RemoveNode(n) => {
+            let valid = node_set.remove(&n);
+            prop_assert_eq!(graph.try_remove_node(n).is_ok(), valid);
+            edge_set.retain(|&(a, b)| a != n && b != n);
+          },
+        }
+        check_invariants(&graph, &node_set, &edge_set)?;
+      }
+  }
+
+  #[test]
+  fn core_decomposition_peels_graph(graph in graph()) {
+    let (order, cores) = graph.core_decomposition();
+    let mut sorted: Vec<(usize, usize)> = cores.clone().into_iter().collect();
+    sorted.sort();
+    prop_assert_eq!(sorted, peeled_cores(&graph));
+    // Each node has at most its core number of adjacent nodes after it
+    for (i, &n) in order.iter().enumerate() {
+      let later = graph.adjlst_of(n).iter()
+        .filter(|a| order[i + 1..].contains(a)).count();
+      prop_assert!(later <= cores[&n], "{}", n);
+    }
+    prop_assert!(order.windows(2).all(|w| cores[&w[0]] <= cores[&w[1]]));
+    let degeneracy = cores.values().copied().max().unwrap_or(0);
+    prop_assert_eq!(graph.degeneracy(), degeneracy);
+    prop_assert!(brute_force(&graph) <= degeneracy + 1);
+  }
+
+  #[test]
+  fn solvers_find_maximum_cliques(graph in graph()) {
+    let size = brute_force(&graph);
+    for solver in &Solver::ALL {
+      let clique = solver::solve(&graph, solver).unwrap().nodes();
+      let result = verify::verify(&graph, &clique).unwrap();
+      prop_assert!(result.is_clique(), "{:?}: {:?}", solver, clique);
+      prop_assert_eq!(result.size, size, "{:?}: {:?}", solver, clique);
+    }
+  }
+
+  #[test]
+  fn reduction_keeps_maximum_cliques(graph in graph()) {
+    let size = brute_force(&graph);
+    let reduction = solver::preprocess(&graph).unwrap();
+    prop_assert_eq!(reduction.graph.nlen() + reduction.removed_nodes,
+      graph.nlen());
+    for solver in &Solver::ALL {
+      let solution = solver::solve_reduced(&graph, &reduction, solver, None)
+        .unwrap();
+      let clique = solution.clique.nodes();
+      let result = verify::verify(&graph, &clique).unwrap();
+      prop_assert!(result.is_clique(), "{:?}: {:?}", solver, clique);
+      prop_assert_eq!(result.size, size, "{:?}: {:?}", solver, clique);
+    }
+  }
+
+  #[test]
+  fn colorings_bound_clique_size(graph in graph()) {
+    let size = brute_force(&graph);
+    for &algorithm in &Algorithm::ALL {
+      let coloring = color::color(&graph, algorithm);
+      prop_assert!(Coloring::new(&graph, coloring.colors().clone()).is_ok(),
+        "{}", algorithm);
+      prop_assert!(coloring.count() >= size, "{}", algorithm);
+    }
+  }
+
+  #[test]
+  fn chromatic_number_is_minimum(graph in graph()) {
+    let result = color::chromatic_number(&graph, None).unwrap();
+    prop_assert!(result.optimal);
+    prop_assert!(Coloring::new(&graph, result.coloring.colors().clone())
+      .is_ok());
+    prop_assert_eq!(result.coloring.count(), brute_force_colors(&graph));
+    prop_assert_eq!(result.clique.len(), brute_force(&graph));
+  }
+
+  #[test]
+  fn bounds_exceed_clique_size(graph in graph()) {
+    let size = brute_force(&graph);
+    let theta = bounds::lovasz_theta_with(&graph, 20);
+    prop_assert!(bounds::theta_clique(theta) >= size, "{}", theta);
+    prop_assert!(bounds::degeneracy(&graph) >= size);
+  }
+
+  #[test]
+  fn unit_weights_find_maximum_cliques(graph in graph()) {
+    let weights = vec![1.0; graph.nlen()];
+    let clique = solver::solve_weighted(&graph, &weights).unwrap().nodes();
+    let result = verify::verify(&graph, &clique).unwrap();
+    prop_assert!(result.is_clique(), "{:?}", clique);
+    prop_assert_eq!(result.size, brute_force(&graph), "{:?}", clique);
+  }
+}